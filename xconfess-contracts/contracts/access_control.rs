@@ -142,7 +142,7 @@ pub fn count_admins(env: &Env) -> u32 {
         .instance()
         .get(&AccessKey::Admins)
         .unwrap_or_else(|| Map::new(env));
-    admins.len() as u32
+    admins.len()
 }
 
 /// Returns the total number of authorized addresses (owner + admins).
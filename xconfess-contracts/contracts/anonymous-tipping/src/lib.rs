@@ -1,12 +1,46 @@
 #![no_std]
 
+#[path = "../../access_control.rs"]
+mod access_control;
+#[path = "../../emergency_pause/mod.rs"]
+mod emergency_pause;
+mod registry;
+
+use access_control::AccessError;
+use emergency_pause::errors::PauseError;
+use registry::ConfessionRegistryClient;
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env,
-    String as SorobanString,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, BytesN,
+    Env, Map, String as SorobanString, Vec,
 };
 
 const SETTLEMENT_EVENT: soroban_sdk::Symbol = symbol_short!("tip_settl");
-const EVENT_VERSION_V1: u32 = 1;
+const ESCROW_EVENT: soroban_sdk::Symbol = symbol_short!("tip_escrw");
+const ASSET_EVENT: soroban_sdk::Symbol = symbol_short!("tip_asset");
+const FEE_EVENT: soroban_sdk::Symbol = symbol_short!("tip_fee");
+const TREASURY_EVENT: soroban_sdk::Symbol = symbol_short!("tip_trsry");
+const STREAM_EVENT: soroban_sdk::Symbol = symbol_short!("tip_strm");
+const LIMITS_EVENT: soroban_sdk::Symbol = symbol_short!("tip_lim");
+/// Version 2 added the settled asset to every tip event.
+const EVENT_VERSION_V2: u32 = 2;
+/// Version 3 split settlement receipts into gross, fee and net amounts.
+const EVENT_VERSION_V3: u32 = 3;
+const BPS_DENOMINATOR: i128 = 10_000;
+/// Settlement IDs per history index chunk.
+const HISTORY_CHUNK_SIZE: u32 = 50;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+/// Nominal ledger close time, used to turn stream durations into ledgers.
+const SECONDS_PER_LEDGER: u64 = 5;
+/// Stream allowances stay valid this many ledgers past the stream's last
+/// period, so the final periods can still be settled.
+const STREAM_SETTLE_GRACE_LEDGERS: u32 = 17_280 * 7;
+/// Temporary limit counters outlive their window by at least this many ledgers.
+const TEMPORARY_TTL_MIN: u32 = 17_280 * 2;
+
+/// Persistent escrow, settlement and per-recipient records are bumped to
+/// ~30 days once under ~7 days remain.
+const PERSISTENT_TTL_THRESHOLD: u32 = 17_280 * 7;
+const PERSISTENT_TTL_EXTEND_TO: u32 = 17_280 * 30;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -15,6 +49,54 @@ pub enum Error {
     MetadataTooLong = 2,
     TotalOverflow = 3,
     NonceOverflow = 4,
+    NotInitialized = 5,
+    InvalidEscrowExpiry = 6,
+    EscrowNotFound = 7,
+    EscrowAlreadySettled = 8,
+    EscrowNotExpired = 9,
+    NotConfessionAuthor = 10,
+    ConfessionNotFound = 11,
+    Unauthorized = 12,
+    AssetNotAccepted = 13,
+    InvalidFee = 14,
+    InsufficientTreasury = 15,
+    InvalidPageLimit = 16,
+    InvalidStream = 20,
+    StreamNotFound = 21,
+    StreamNotActive = 22,
+    AllowanceExhausted = 23,
+    BelowMinTip = 24,
+    AboveMaxTip = 25,
+    DailyCapExceeded = 26,
+    RateLimited = 27,
+    InvalidLimits = 28,
+    ContractPaused = 29,
+    AlreadyPaused = 30,
+    NotPaused = 31,
+    InvalidRoleChange = 32,
+    InvalidBatchSize = 33,
+    InsufficientBalance = 34,
+}
+
+impl From<AccessError> for Error {
+    fn from(err: AccessError) -> Self {
+        match err {
+            AccessError::NotInitialized => Error::NotInitialized,
+            AccessError::NotOwner | AccessError::NotAuthorized => Error::Unauthorized,
+            _ => Error::InvalidRoleChange,
+        }
+    }
+}
+
+impl From<PauseError> for Error {
+    fn from(err: PauseError) -> Self {
+        match err {
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::ContractPaused => Error::ContractPaused,
+        }
+    }
 }
 
 #[contract]
@@ -23,110 +105,1439 @@ pub struct AnonymousTipping;
 #[contracttype]
 #[derive(Clone)]
 enum DataKey {
-    RecipientTotal(Address),
+    RecipientTotal(Address, Address),
+    RecipientAssets(Address),
     SettlementNonce,
+    AcceptedAssets,
+    FeeBps,
+    Treasury(Address),
+    EscrowConfig,
+    EscrowNonce,
+    Escrow(u64),
+    Settlement(u64),
+    HistoryCount(Address),
+    HistoryChunk(Address, u32),
+    StreamNonce,
+    Stream(u64),
+    /// Latest allowance expiry ledger set for a (tipper, asset) pair.
+    StreamAllowanceExpiry(Address, Address),
+    Limits(Address),
+    RateLimit,
+    DailyTotal(Address, Address, u64),
+    TipperWindow(Address),
+}
+
+/// Per-asset tip bounds, in the asset's smallest unit. A zero field disables
+/// that check.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TipLimits {
+    pub min_amount: i128,
+    pub max_amount: i128,
+    /// Most a recipient may be settled in this asset per UTC day (gross).
+    pub recipient_daily_cap: i128,
+}
+
+/// At most `max_tips` tipping actions per tipper in any fixed
+/// `window_secs` window. `max_tips == 0` disables the limit.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RateLimit {
+    pub max_tips: u32,
+    pub window_secs: u64,
+}
+
+/// A tipper's action count in the window starting at `window_start`.
+#[contracttype]
+#[derive(Clone)]
+struct TipperWindow {
+    window_start: u64,
+    count: u32,
+}
+
+/// Emitted when an asset's tip limits or the tipper rate limit change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LimitsUpdatedEvent {
+    pub event_version: u32,
+    pub asset: Option<Address>,
+    pub limits: TipLimits,
+    pub rate_limit: RateLimit,
+    pub actor: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StreamState {
+    Active,
+    Cancelled,
+}
+
+/// Recurring tip paid out of `tipper`'s allowance to this contract, one
+/// `amount_per_period` for each `period_secs` elapsed since `start`, up to
+/// `max_periods`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TipStream {
+    pub id: u64,
+    pub tipper: Address,
+    pub recipient: Address,
+    pub asset: Address,
+    pub amount_per_period: i128,
+    pub period_secs: u64,
+    pub max_periods: u32,
+    pub periods_paid: u32,
+    pub start: u64,
+    /// Set on cancel; periods elapsed before this remain payable.
+    pub cancelled_at: u64,
+    pub state: StreamState,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamEvent {
+    pub event_version: u32,
+    pub stream_id: u64,
+    pub tipper: Address,
+    pub recipient: Address,
+    pub asset: Address,
+    pub amount_per_period: i128,
+    pub periods_paid: u32,
+    pub state: StreamState,
+}
+
+/// Compact per-settlement record, keyed by settlement ID. `amount` is the
+/// net amount credited to the recipient.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementRecord {
+    pub settlement_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub asset: Address,
+    pub timestamp: u64,
+    pub proof_present: bool,
+}
+
+/// One page of settlement history. `next_cursor` is `None` once the index
+/// is exhausted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementPage {
+    pub items: Vec<SettlementRecord>,
+    pub next_cursor: Option<u64>,
+}
+
+/// Registry used to resolve confession authors and how long escrows stay
+/// claim-only before the tipper may reclaim them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowConfig {
+    pub registry: Address,
+    pub expiry_secs: u64,
+}
+
+/// What an escrowed tip is addressed to: a registry confession ID, or the
+/// confession's content hash when the ID is not known to the tipper.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TipTarget {
+    Confession(u64),
+    Commitment(BytesN<32>),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EscrowState {
+    Pending,
+    Claimed,
+    Refunded,
 }
 
+/// Tip held by the contract until the confession author claims it or the
+/// tipper reclaims it after `expires_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowedTip {
+    pub id: u64,
+    pub tipper: Address,
+    pub target: TipTarget,
+    pub asset: Address,
+    pub amount: i128,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub state: EscrowState,
+}
+
+/// Receipt for a settled tip. `amount` is the net credited to the recipient:
+/// `gross_amount - fee_amount`.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SettlementReceiptEvent {
     pub event_version: u32,
     pub settlement_id: u64,
     pub recipient: Address,
+    pub asset: Address,
+    pub gross_amount: i128,
+    pub fee_amount: i128,
     pub amount: i128,
     pub proof_metadata: SorobanString,
     pub proof_present: bool,
     pub timestamp: u64,
 }
 
+/// Emitted when an escrow is opened, claimed or refunded. `counterparty` is
+/// the tipper for `Pending`/`Refunded` and the claiming author for `Claimed`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowSettlementEvent {
+    pub event_version: u32,
+    pub escrow_id: u64,
+    pub target: TipTarget,
+    pub counterparty: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub state: EscrowState,
+    pub timestamp: u64,
+}
+
+/// Emitted when the accepted-asset allow-list changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetListEvent {
+    pub event_version: u32,
+    pub asset: Address,
+    pub accepted: bool,
+    pub actor: Address,
+}
+
+/// Emitted whenever the platform fee changes so tippers can audit it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeUpdatedEvent {
+    pub event_version: u32,
+    pub old_fee_bps: u32,
+    pub new_fee_bps: u32,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// Emitted when the owner withdraws accumulated fees.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryWithdrawalEvent {
+    pub event_version: u32,
+    pub asset: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub remaining: i128,
+}
+
+fn fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get::<_, u32>(&DataKey::FeeBps)
+        .unwrap_or(0)
+}
+
+/// Platform fee on `gross`, rounded down. Split to avoid overflowing
+/// `gross * bps` for very large amounts.
+fn fee_for(gross: i128, bps: u32) -> i128 {
+    let bps = bps as i128;
+    gross / BPS_DENOMINATOR * bps + gross % BPS_DENOMINATOR * bps / BPS_DENOMINATOR
+}
+
+fn treasury_balance(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get::<_, i128>(&DataKey::Treasury(asset.clone()))
+        .unwrap_or(0)
+}
+
+fn validate_metadata(
+    env: &Env,
+    proof_metadata: Option<SorobanString>,
+) -> Result<SorobanString, Error> {
+    match proof_metadata {
+        Some(value) => {
+            if value.len() > AnonymousTipping::MAX_PROOF_METADATA_LEN {
+                return Err(Error::MetadataTooLong);
+            }
+            Ok(value)
+        }
+        None => Ok(SorobanString::from_str(env, "")),
+    }
+}
+
+fn tip_limits(env: &Env, asset: &Address) -> TipLimits {
+    env.storage()
+        .instance()
+        .get::<_, TipLimits>(&DataKey::Limits(asset.clone()))
+        .unwrap_or_default()
+}
+
+fn rate_limit(env: &Env) -> RateLimit {
+    env.storage()
+        .instance()
+        .get::<_, RateLimit>(&DataKey::RateLimit)
+        .unwrap_or_default()
+}
+
+fn extend_temporary(env: &Env, key: &DataKey, secs: u64) {
+    let ledgers = (secs / 5).min(u32::MAX as u64) as u32;
+    let extend_to = ledgers.max(TEMPORARY_TTL_MIN).min(env.storage().max_ttl());
+    env.storage().temporary().extend_ttl(key, extend_to, extend_to);
+}
+
+/// Check `amount` against the asset's min/max tip bounds.
+fn check_tip_amount(env: &Env, asset: &Address, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidTipAmount);
+    }
+    let limits = tip_limits(env, asset);
+    if limits.min_amount > 0 && amount < limits.min_amount {
+        return Err(Error::BelowMinTip);
+    }
+    if limits.max_amount > 0 && amount > limits.max_amount {
+        return Err(Error::AboveMaxTip);
+    }
+    Ok(())
+}
+
+/// Count one tipping action by `tipper` against the rate limit.
+fn record_tipper_action(env: &Env, tipper: &Address) -> Result<(), Error> {
+    let limit = rate_limit(env);
+    if limit.max_tips == 0 {
+        return Ok(());
+    }
+
+    let now = env.ledger().timestamp();
+    let key = DataKey::TipperWindow(tipper.clone());
+    let mut window = env
+        .storage()
+        .temporary()
+        .get::<_, TipperWindow>(&key)
+        .filter(|w| now < w.window_start.saturating_add(limit.window_secs))
+        .unwrap_or(TipperWindow {
+            window_start: now,
+            count: 0,
+        });
+    if window.count >= limit.max_tips {
+        return Err(Error::RateLimited);
+    }
+
+    window.count += 1;
+    env.storage().temporary().set(&key, &window);
+    extend_temporary(env, &key, limit.window_secs);
+    Ok(())
+}
+
+/// Add `gross` to the recipient's settled total for `asset` today, failing
+/// if that would exceed the asset's daily cap.
+fn record_daily_total(
+    env: &Env,
+    recipient: &Address,
+    asset: &Address,
+    gross: i128,
+) -> Result<(), Error> {
+    let cap = tip_limits(env, asset).recipient_daily_cap;
+    if cap == 0 {
+        return Ok(());
+    }
+
+    let day = env.ledger().timestamp() / SECONDS_PER_DAY;
+    let key = DataKey::DailyTotal(recipient.clone(), asset.clone(), day);
+    let total = env
+        .storage()
+        .temporary()
+        .get::<_, i128>(&key)
+        .unwrap_or(0)
+        .checked_add(gross)
+        .ok_or(Error::TotalOverflow)?;
+    if total > cap {
+        return Err(Error::DailyCapExceeded);
+    }
+
+    env.storage().temporary().set(&key, &total);
+    extend_temporary(env, &key, SECONDS_PER_DAY);
+    Ok(())
+}
+
+fn accepted_assets(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get::<_, Vec<Address>>(&DataKey::AcceptedAssets)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn require_accepted(env: &Env, asset: &Address) -> Result<(), Error> {
+    if !env.storage().instance().has(&DataKey::AcceptedAssets) {
+        return Err(Error::NotInitialized);
+    }
+    if !accepted_assets(env).contains(asset) {
+        return Err(Error::AssetNotAccepted);
+    }
+    Ok(())
+}
+
+fn extend_persistent(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+}
+
+fn recipient_total(env: &Env, recipient: &Address, asset: &Address) -> i128 {
+    let key = DataKey::RecipientTotal(recipient.clone(), asset.clone());
+    let total = env.storage().persistent().get::<_, i128>(&key);
+    if total.is_some() {
+        extend_persistent(env, &key);
+    }
+    total.unwrap_or(0_i128)
+}
+
+/// Store a new (recipient, asset) total, recording the asset in the
+/// recipient's asset index the first time it is tipped in.
+fn write_recipient_total(env: &Env, recipient: &Address, asset: &Address, total: i128) {
+    let key = DataKey::RecipientTotal(recipient.clone(), asset.clone());
+    if !env.storage().persistent().has(&key) {
+        let index_key = DataKey::RecipientAssets(recipient.clone());
+        let mut assets = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<Address>>(&index_key)
+            .unwrap_or_else(|| Vec::new(env));
+        assets.push_back(asset.clone());
+        env.storage().persistent().set(&index_key, &assets);
+        extend_persistent(env, &index_key);
+    }
+    env.storage().persistent().set(&key, &total);
+    extend_persistent(env, &key);
+}
+
+fn escrow_config(env: &Env) -> Result<EscrowConfig, Error> {
+    env.storage()
+        .instance()
+        .get::<_, EscrowConfig>(&DataKey::EscrowConfig)
+        .ok_or(Error::NotInitialized)
+}
+
+fn history_count(env: &Env, recipient: &Address) -> u64 {
+    let key = DataKey::HistoryCount(recipient.clone());
+    let count = env.storage().persistent().get(&key).unwrap_or(0u64);
+    if count > 0 {
+        extend_persistent(env, &key);
+    }
+    count
+}
+
+fn read_history_chunk(env: &Env, recipient: &Address, chunk: u32) -> Vec<u64> {
+    let key = DataKey::HistoryChunk(recipient.clone(), chunk);
+    match env.storage().persistent().get(&key) {
+        Some(ids) => {
+            extend_persistent(env, &key);
+            ids
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Append `settlement_id` to a recipient's history, starting a new chunk
+/// when the last one is full.
+fn append_history(env: &Env, recipient: &Address, settlement_id: u64) {
+    let count = history_count(env, recipient);
+    let chunk = (count / HISTORY_CHUNK_SIZE as u64) as u32;
+
+    let mut ids = read_history_chunk(env, recipient, chunk);
+    ids.push_back(settlement_id);
+
+    let chunk_key = DataKey::HistoryChunk(recipient.clone(), chunk);
+    env.storage().persistent().set(&chunk_key, &ids);
+    extend_persistent(env, &chunk_key);
+
+    let count_key = DataKey::HistoryCount(recipient.clone());
+    env.storage().persistent().set(&count_key, &(count + 1));
+    extend_persistent(env, &count_key);
+}
+
+fn read_settlement(env: &Env, settlement_id: u64) -> Option<SettlementRecord> {
+    let key = DataKey::Settlement(settlement_id);
+    let record = env.storage().persistent().get::<_, SettlementRecord>(&key);
+    if record.is_some() {
+        extend_persistent(env, &key);
+    }
+    record
+}
+
+/// Page through a recipient's settlements in settlement order, starting at
+/// position `cursor`.
+fn history_page(
+    env: &Env,
+    recipient: &Address,
+    cursor: Option<u64>,
+    limit: u32,
+) -> Result<SettlementPage, Error> {
+    if limit == 0 || limit > AnonymousTipping::MAX_PAGE_SIZE {
+        return Err(Error::InvalidPageLimit);
+    }
+
+    let count = history_count(env, recipient);
+    let mut items = Vec::new(env);
+    let mut position = cursor.unwrap_or(0);
+
+    let mut chunk_index = u32::MAX;
+    let mut chunk = Vec::new(env);
+    while position < count && items.len() < limit {
+        let wanted = (position / HISTORY_CHUNK_SIZE as u64) as u32;
+        if wanted != chunk_index {
+            chunk_index = wanted;
+            chunk = read_history_chunk(env, recipient, chunk_index);
+        }
+
+        let id = chunk
+            .get((position % HISTORY_CHUNK_SIZE as u64) as u32)
+            .expect("settlement index out of sync");
+        items.push_back(read_settlement(env, id).expect("settlement record missing"));
+        position += 1;
+    }
+
+    Ok(SettlementPage {
+        items,
+        next_cursor: if position < count { Some(position) } else { None },
+    })
+}
+
+fn read_escrow(env: &Env, id: u64) -> Result<EscrowedTip, Error> {
+    let key = DataKey::Escrow(id);
+    let escrow = env
+        .storage()
+        .persistent()
+        .get::<_, EscrowedTip>(&key)
+        .ok_or(Error::EscrowNotFound)?;
+    extend_persistent(env, &key);
+    Ok(escrow)
+}
+
+fn write_escrow(env: &Env, escrow: &EscrowedTip) {
+    let key = DataKey::Escrow(escrow.id);
+    env.storage().persistent().set(&key, escrow);
+    extend_persistent(env, &key);
+}
+
+fn emit_escrow_event(env: &Env, escrow: &EscrowedTip, counterparty: Address) {
+    let payload = EscrowSettlementEvent {
+        event_version: EVENT_VERSION_V2,
+        escrow_id: escrow.id,
+        target: escrow.target.clone(),
+        counterparty,
+        asset: escrow.asset.clone(),
+        amount: escrow.amount,
+        state: escrow.state,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish((ESCROW_EVENT, escrow.id), payload);
+}
+
+fn read_stream(env: &Env, id: u64) -> Result<TipStream, Error> {
+    let key = DataKey::Stream(id);
+    let stream = env
+        .storage()
+        .persistent()
+        .get::<_, TipStream>(&key)
+        .ok_or(Error::StreamNotFound)?;
+    extend_persistent(env, &key);
+    Ok(stream)
+}
+
+fn write_stream(env: &Env, stream: &TipStream) {
+    let key = DataKey::Stream(stream.id);
+    env.storage().persistent().set(&key, stream);
+    extend_persistent(env, &key);
+}
+
+/// Periods of `stream` that have elapsed (up to cancellation) but not been paid.
+fn stream_periods_due(env: &Env, stream: &TipStream) -> u32 {
+    let until = match stream.state {
+        StreamState::Active => env.ledger().timestamp(),
+        StreamState::Cancelled => stream.cancelled_at,
+    };
+    let elapsed = until.saturating_sub(stream.start) / stream.period_secs;
+    let elapsed = elapsed.min(stream.max_periods as u64) as u32;
+    elapsed.saturating_sub(stream.periods_paid)
+}
+
+fn emit_stream_event(env: &Env, stream: &TipStream) {
+    let payload = StreamEvent {
+        event_version: EVENT_VERSION_V3,
+        stream_id: stream.id,
+        tipper: stream.tipper.clone(),
+        recipient: stream.recipient.clone(),
+        asset: stream.asset.clone(),
+        amount_per_period: stream.amount_per_period,
+        periods_paid: stream.periods_paid,
+        state: stream.state,
+    };
+    env.events().publish((STREAM_EVENT, stream.id), payload);
+}
+
+/// Expiry ledger of the allowance last set for `tipper` in `asset`, if it
+/// has not passed yet.
+fn stream_allowance_expiry(env: &Env, asset: &Address, tipper: &Address) -> Option<u32> {
+    let key = DataKey::StreamAllowanceExpiry(tipper.clone(), asset.clone());
+    env.storage()
+        .persistent()
+        .get::<_, u32>(&key)
+        .filter(|expiry| *expiry >= env.ledger().sequence())
+}
+
+/// Ledger by which `stream` has run its course: its last period plus
+/// `STREAM_SETTLE_GRACE_LEDGERS`, capped at the furthest ledger an
+/// allowance may currently be set to (`max_ttl - 1` ledgers ahead).
+///
+/// A stream outliving the cap keeps paying until the allowance expires;
+/// later periods fail with `AllowanceExhausted` until the tipper re-approves.
+fn stream_end_ledger(env: &Env, stream: &TipStream) -> u32 {
+    let duration = stream.period_secs.saturating_mul(stream.max_periods as u64);
+    let ledgers = duration.div_ceil(SECONDS_PER_LEDGER)
+        .saturating_add(STREAM_SETTLE_GRACE_LEDGERS as u64)
+        .min((env.storage().max_ttl() - 1) as u64) as u32;
+    env.ledger().sequence() + ledgers
+}
+
+/// Set `tipper`'s allowance to this contract to `amount`, valid until
+/// `expiry` or the expiry already set for another stream, whichever is later.
+/// Allowances are shared by all of a tipper's streams in an asset, so callers
+/// adjust the current allowance rather than replacing it.
+fn set_stream_allowance(
+    env: &Env,
+    asset: &Address,
+    tipper: &Address,
+    amount: i128,
+    expiry: u32,
+) {
+    let expiry = stream_allowance_expiry(env, asset, tipper)
+        .unwrap_or(0)
+        .max(expiry);
+    token::Client::new(env, asset).approve(
+        tipper,
+        &env.current_contract_address(),
+        &amount,
+        &expiry,
+    );
+
+    let key = DataKey::StreamAllowanceExpiry(tipper.clone(), asset.clone());
+    env.storage().persistent().set(&key, &expiry);
+    extend_persistent(env, &key);
+}
+
+/// Where a settlement's funds come from.
+#[derive(Clone, Copy)]
+enum Funding<'a> {
+    /// The payer authorized this invocation and pays directly.
+    Direct(&'a Address),
+    /// Funds this contract already holds (escrows).
+    Held,
+    /// Pulled from the payer against an allowance granted to this contract.
+    Allowance(&'a Address),
+}
+
+fn pull(
+    env: &Env,
+    token: &token::Client,
+    funding: Funding,
+    to: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let contract = env.current_contract_address();
+    match funding {
+        Funding::Direct(from) => token.transfer(from, to, &amount),
+        Funding::Held => {
+            if *to != contract {
+                token.transfer(&contract, to, &amount);
+            }
+        }
+        Funding::Allowance(from) => {
+            if token.try_transfer_from(&contract, from, to, &amount).is_err() {
+                return Err(Error::AllowanceExhausted);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Move `gross` of `asset` from `funding` to `recipient` less the platform
+/// fee, which is kept in the treasury. Bumps the (recipient, asset) net total
+/// and settlement nonce, records the settlement in the recipient's history
+/// and emits the settlement receipt.
+fn settle(
+    env: &Env,
+    asset: &Address,
+    funding: Funding,
+    recipient: Address,
+    gross: i128,
+    metadata: SorobanString,
+) -> Result<u64, Error> {
+    record_daily_total(env, &recipient, asset, gross)?;
+
+    let fee = fee_for(gross, fee_bps(env));
+    let amount = gross - fee;
+
+    let previous = recipient_total(env, &recipient, asset);
+    let next_total = previous
+        .checked_add(amount)
+        .ok_or(Error::TotalOverflow)?;
+    let next_treasury = treasury_balance(env, asset)
+        .checked_add(fee)
+        .ok_or(Error::TotalOverflow)?;
+
+    let settlement_id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::SettlementNonce)
+        .unwrap_or(0_u64)
+        .checked_add(1)
+        .ok_or(Error::NonceOverflow)?;
+
+    let token = token::Client::new(env, asset);
+    pull(env, &token, funding, &recipient, amount)?;
+    if fee > 0 {
+        pull(env, &token, funding, &env.current_contract_address(), fee)?;
+    }
+
+    write_recipient_total(env, &recipient, asset, next_total);
+    if fee > 0 {
+        env.storage()
+            .instance()
+            .set(&DataKey::Treasury(asset.clone()), &next_treasury);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::SettlementNonce, &settlement_id);
+
+    let timestamp = env.ledger().timestamp();
+    let record = SettlementRecord {
+        settlement_id,
+        recipient: recipient.clone(),
+        amount,
+        asset: asset.clone(),
+        timestamp,
+        proof_present: metadata.len() > 0,
+    };
+    let record_key = DataKey::Settlement(settlement_id);
+    env.storage().persistent().set(&record_key, &record);
+    extend_persistent(env, &record_key);
+    append_history(env, &recipient, settlement_id);
+
+    let payload = SettlementReceiptEvent {
+        event_version: EVENT_VERSION_V3,
+        settlement_id,
+        recipient: recipient.clone(),
+        asset: asset.clone(),
+        gross_amount: gross,
+        fee_amount: fee,
+        amount,
+        proof_metadata: metadata.clone(),
+        proof_present: record.proof_present,
+        timestamp,
+    };
+    env.events().publish((SETTLEMENT_EVENT, recipient), payload);
+
+    Ok(settlement_id)
+}
+
 #[contractimpl]
 impl AnonymousTipping {
     pub const MAX_PROOF_METADATA_LEN: u32 = 128;
+    /// Upper bound on the escrow expiry window (one year).
+    pub const MAX_ESCROW_EXPIRY_SECS: u64 = 365 * 24 * 60 * 60;
+    /// Upper bound on the platform fee (10%).
+    pub const MAX_FEE_BPS: u32 = 1_000;
+    /// Maximum records returned by a settlement history page.
+    pub const MAX_PAGE_SIZE: u32 = 50;
+    /// Upper bound on a tip stream's `max_periods`.
+    pub const MAX_STREAM_PERIODS: u32 = 1_000;
+    /// Maximum periods paid out by a single `settle_due` call.
+    pub const MAX_SETTLE_PERIODS: u32 = 50;
+    /// Maximum entries in a single `send_tips_batch` call.
+    pub const MAX_BATCH_SIZE: u32 = 25;
 
-    /// Initialize the tipping contract
-    pub fn init(env: Env) {
-        if env.storage().instance().has(&DataKey::SettlementNonce) {
+    /// Initialize the tipping contract with its owner and a first accepted
+    /// SAC/SEP-41 tip asset.
+    ///
+    /// The owner is fixed on first call; repeated calls are no-ops.
+    pub fn init(env: Env, owner: Address, asset: Address) {
+        if access_control::get_owner(&env).is_ok() {
             return;
         }
 
+        access_control::init_owner(&env, &owner).expect("owner initialization failed");
+        let mut assets = Vec::new(&env);
+        assets.push_back(asset);
+        env.storage().instance().set(&DataKey::AcceptedAssets, &assets);
+        if !env.storage().instance().has(&DataKey::SettlementNonce) {
+            env.storage()
+                .instance()
+                .set(&DataKey::SettlementNonce, &0_u64);
+        }
+    }
+
+    /// Grant `target` the admin role (owner only).
+    pub fn grant_admin(env: Env, caller: Address, target: Address) -> Result<(), Error> {
+        access_control::grant_admin(&env, &caller, &target).map_err(Into::into)
+    }
+
+    /// Revoke `target`'s admin role (owner only).
+    pub fn revoke_admin(env: Env, caller: Address, target: Address) -> Result<(), Error> {
+        access_control::revoke_admin(&env, &caller, &target).map_err(Into::into)
+    }
+
+    /// Transfer ownership to `new_owner` (owner only).
+    pub fn transfer_owner(env: Env, caller: Address, new_owner: Address) -> Result<(), Error> {
+        access_control::transfer_ownership(&env, &caller, &new_owner).map_err(Into::into)
+    }
+
+    /// Pause every tip, escrow and stream operation. Admin or owner
+    /// only. Reads and admin functions remain available.
+    pub fn pause(env: Env, caller: Address, reason: SorobanString) -> Result<(), Error> {
+        access_control::require_admin_or_owner(&env, &caller)?;
+        if emergency_pause::is_paused(&env) {
+            return Err(Error::AlreadyPaused);
+        }
+        emergency_pause::set_paused_internal(&env, true);
+        emergency_pause::events::emit_paused(&env, &caller, reason);
+        Ok(())
+    }
+
+    /// Lift a pause. Admin or owner only.
+    pub fn unpause(env: Env, caller: Address, reason: SorobanString) -> Result<(), Error> {
+        access_control::require_admin_or_owner(&env, &caller)?;
+        if !emergency_pause::is_paused(&env) {
+            return Err(Error::NotPaused);
+        }
+        emergency_pause::set_paused_internal(&env, false);
+        emergency_pause::events::emit_unpaused(&env, &caller, reason);
+        Ok(())
+    }
+
+    /// Whether the contract is paused.
+    pub fn is_paused(env: Env) -> bool {
+        emergency_pause::is_paused(&env)
+    }
+
+    /// Set the min/max tip and recipient daily cap for `asset`. Admin or
+    /// owner only; zero disables a bound.
+    pub fn set_tip_limits(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        limits: TipLimits,
+    ) -> Result<(), Error> {
+        access_control::require_admin_or_owner(&env, &caller)?;
+
+        if limits.min_amount < 0 || limits.max_amount < 0 || limits.recipient_daily_cap < 0 {
+            return Err(Error::InvalidLimits);
+        }
+        if limits.max_amount > 0 && limits.min_amount > limits.max_amount {
+            return Err(Error::InvalidLimits);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Limits(asset.clone()), &limits);
+
+        let payload = LimitsUpdatedEvent {
+            event_version: EVENT_VERSION_V3,
+            asset: Some(asset),
+            limits,
+            rate_limit: rate_limit(&env),
+            actor: caller,
+        };
+        env.events().publish((LIMITS_EVENT,), payload);
+        Ok(())
+    }
+
+    /// Tip limits currently applied to `asset`.
+    pub fn get_tip_limits(env: Env, asset: Address) -> TipLimits {
+        tip_limits(&env, &asset)
+    }
+
+    /// Set the per-tipper rate limit. Admin or owner only; `max_tips == 0`
+    /// disables it.
+    pub fn set_rate_limit(env: Env, caller: Address, rate_limit: RateLimit) -> Result<(), Error> {
+        access_control::require_admin_or_owner(&env, &caller)?;
+
+        if rate_limit.max_tips > 0 && rate_limit.window_secs == 0 {
+            return Err(Error::InvalidLimits);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RateLimit, &rate_limit);
+
+        let payload = LimitsUpdatedEvent {
+            event_version: EVENT_VERSION_V3,
+            asset: None,
+            limits: TipLimits::default(),
+            rate_limit,
+            actor: caller,
+        };
+        env.events().publish((LIMITS_EVENT,), payload);
+        Ok(())
+    }
+
+    /// The per-tipper rate limit.
+    pub fn get_rate_limit(env: Env) -> RateLimit {
+        rate_limit(&env)
+    }
+
+    /// Add `asset` to the accepted tip assets. Admin or owner only.
+    pub fn add_accepted_asset(env: Env, caller: Address, asset: Address) -> Result<(), Error> {
+        access_control::require_admin_or_owner(&env, &caller)?;
+
+        let mut assets = accepted_assets(&env);
+        if assets.contains(&asset) {
+            return Ok(());
+        }
+        assets.push_back(asset.clone());
+        env.storage().instance().set(&DataKey::AcceptedAssets, &assets);
+
+        let payload = AssetListEvent {
+            event_version: EVENT_VERSION_V2,
+            asset: asset.clone(),
+            accepted: true,
+            actor: caller,
+        };
+        env.events().publish((ASSET_EVENT, asset), payload);
+        Ok(())
+    }
+
+    /// Stop accepting `asset` for new tips. Admin or owner only.
+    ///
+    /// Existing totals and pending escrows in that asset are unaffected.
+    pub fn remove_accepted_asset(env: Env, caller: Address, asset: Address) -> Result<(), Error> {
+        access_control::require_admin_or_owner(&env, &caller)?;
+
+        let mut assets = accepted_assets(&env);
+        let index = assets.first_index_of(&asset).ok_or(Error::AssetNotAccepted)?;
+        assets.remove(index);
+        env.storage().instance().set(&DataKey::AcceptedAssets, &assets);
+
+        let payload = AssetListEvent {
+            event_version: EVENT_VERSION_V2,
+            asset: asset.clone(),
+            accepted: false,
+            actor: caller,
+        };
+        env.events().publish((ASSET_EVENT, asset), payload);
+        Ok(())
+    }
+
+    /// Assets currently accepted for new tips, in the order they were added.
+    pub fn get_accepted_assets(env: Env) -> Vec<Address> {
+        accepted_assets(&env)
+    }
+
+    /// Set the platform fee, in basis points, taken from every settled tip.
+    /// Owner only; bounded by `MAX_FEE_BPS`.
+    pub fn set_fee_bps(env: Env, caller: Address, fee_bps: u32) -> Result<(), Error> {
+        access_control::require_owner(&env, &caller)?;
+
+        if fee_bps > Self::MAX_FEE_BPS {
+            return Err(Error::InvalidFee);
+        }
+
+        let old_fee_bps = self::fee_bps(&env);
+        env.storage().instance().set(&DataKey::FeeBps, &fee_bps);
+
+        let payload = FeeUpdatedEvent {
+            event_version: EVENT_VERSION_V3,
+            old_fee_bps,
+            new_fee_bps: fee_bps,
+            actor: caller,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish((FEE_EVENT,), payload);
+        Ok(())
+    }
+
+    /// Current platform fee in basis points.
+    pub fn get_fee_bps(env: Env) -> u32 {
+        fee_bps(&env)
+    }
+
+    /// Fees accumulated in `asset` and not yet withdrawn.
+    pub fn get_treasury(env: Env, asset: Address) -> i128 {
+        treasury_balance(&env, &asset)
+    }
+
+    /// Withdraw accumulated `asset` fees to `to`. Owner only.
+    pub fn withdraw_treasury(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        access_control::require_owner(&env, &caller)?;
+
+        if amount <= 0 {
+            return Err(Error::InvalidTipAmount);
+        }
+        let remaining = treasury_balance(&env, &asset) - amount;
+        if remaining < 0 {
+            return Err(Error::InsufficientTreasury);
+        }
+
         env.storage()
             .instance()
-            .set(&DataKey::SettlementNonce, &0_u64);
+            .set(&DataKey::Treasury(asset.clone()), &remaining);
+        token::Client::new(&env, &asset).transfer(&env.current_contract_address(), &to, &amount);
+
+        let payload = TreasuryWithdrawalEvent {
+            event_version: EVENT_VERSION_V3,
+            asset: asset.clone(),
+            to,
+            amount,
+            remaining,
+        };
+        env.events().publish((TREASURY_EVENT, asset), payload);
+        Ok(())
     }
 
-    /// Send anonymous tip to a recipient
+    /// Send anonymous tip in `asset` from `tipper` to a recipient
     pub fn send_tip(
         env: Env,
+        tipper: Address,
         recipient: Address,
+        asset: Address,
         amount: i128,
     ) -> Result<u64, Error> {
-        Self::send_tip_with_proof(env, recipient, amount, None)
+        Self::send_tip_with_proof(env, tipper, recipient, asset, amount, None)
     }
 
     /// Send anonymous tip with optional bounded settlement proof metadata.
+    ///
+    /// Pulls `amount` of an accepted `asset` from `tipper` (who must
+    /// authorize the call) and transfers it to `recipient`.
+    /// The platform fee is deducted from `amount` and kept in the treasury.
     pub fn send_tip_with_proof(
         env: Env,
+        tipper: Address,
         recipient: Address,
+        asset: Address,
         amount: i128,
         proof_metadata: Option<SorobanString>,
     ) -> Result<u64, Error> {
-        if amount <= 0 {
-            return Err(Error::InvalidTipAmount);
+        tipper.require_auth();
+
+        emergency_pause::assert_not_paused(&env)?;
+        require_accepted(&env, &asset)?;
+        check_tip_amount(&env, &asset, amount)?;
+        let metadata = validate_metadata(&env, proof_metadata)?;
+        record_tipper_action(&env, &tipper)?;
+
+        let funding = Funding::Direct(&tipper);
+        settle(&env, &asset, funding, recipient, amount, metadata)
+    }
+
+    /// Send one tip of `asset` per `(recipient, amount)` entry under a single
+    /// authorization from `tipper`, returning the settlement IDs in order.
+    ///
+    /// Amounts and the tipper's rate limit are checked for every entry, and
+    /// the tipper's balance against the batch total, before any funds move. A
+    /// failure on any entry reverts the whole batch. Each entry emits its own
+    /// settlement receipt.
+    pub fn send_tips_batch(
+        env: Env,
+        tipper: Address,
+        asset: Address,
+        tips: Vec<(Address, i128)>,
+    ) -> Result<Vec<u64>, Error> {
+        tipper.require_auth();
+
+        emergency_pause::assert_not_paused(&env)?;
+        if tips.is_empty() || tips.len() > Self::MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
         }
+        require_accepted(&env, &asset)?;
 
-        let metadata = match proof_metadata {
-            Some(value) => {
-                if value.len() > Self::MAX_PROOF_METADATA_LEN {
-                    return Err(Error::MetadataTooLong);
-                }
-                value
-            }
-            None => SorobanString::from_str(&env, ""),
-        };
+        let mut batch_total: i128 = 0;
+        for (_, amount) in tips.iter() {
+            check_tip_amount(&env, &asset, amount)?;
+            record_tipper_action(&env, &tipper)?;
+            batch_total = batch_total
+                .checked_add(amount)
+                .ok_or(Error::TotalOverflow)?;
+        }
+        // Fees come out of each gross amount, so the batch total is all the
+        // tipper pays.
+        if token::Client::new(&env, &asset).balance(&tipper) < batch_total {
+            return Err(Error::InsufficientBalance);
+        }
 
-        let previous = env
+        let metadata = SorobanString::from_str(&env, "");
+        let mut settlement_ids = Vec::new(&env);
+        for (recipient, amount) in tips.iter() {
+            let funding = Funding::Direct(&tipper);
+            let settlement_id = settle(
+                &env,
+                &asset,
+                funding,
+                recipient,
+                amount,
+                metadata.clone(),
+            )?;
+            settlement_ids.push_back(settlement_id);
+        }
+        Ok(settlement_ids)
+    }
+
+    /// Start a recurring tip of `amount_per_period` every `period_secs`, for at
+    /// most `max_periods` periods, and return the stream ID.
+    ///
+    /// Nothing is transferred up front. Instead the tipper's token allowance
+    /// to this contract is raised by the stream's full value, and each period
+    /// is pulled from it by `settle_due`. The allowance is kept valid until
+    /// the stream ends, capped at the ledger's maximum TTL.
+    pub fn create_tip_stream(
+        env: Env,
+        tipper: Address,
+        recipient: Address,
+        asset: Address,
+        amount_per_period: i128,
+        period_secs: u64,
+        max_periods: u32,
+    ) -> Result<u64, Error> {
+        tipper.require_auth();
+
+        emergency_pause::assert_not_paused(&env)?;
+        require_accepted(&env, &asset)?;
+        check_tip_amount(&env, &asset, amount_per_period)?;
+        if period_secs == 0 || max_periods == 0 || max_periods > Self::MAX_STREAM_PERIODS {
+            return Err(Error::InvalidStream);
+        }
+        let total = amount_per_period
+            .checked_mul(max_periods as i128)
+            .ok_or(Error::TotalOverflow)?;
+        record_tipper_action(&env, &tipper)?;
+
+        let id = env
             .storage()
             .instance()
-            .get::<_, i128>(&DataKey::RecipientTotal(recipient.clone()))
-            .unwrap_or(0_i128);
-        let next_total = previous
-            .checked_add(amount)
+            .get::<_, u64>(&DataKey::StreamNonce)
+            .unwrap_or(0_u64)
+            .checked_add(1)
+            .ok_or(Error::NonceOverflow)?;
+
+        let stream = TipStream {
+            id,
+            tipper,
+            recipient,
+            asset,
+            amount_per_period,
+            period_secs,
+            max_periods,
+            periods_paid: 0,
+            start: env.ledger().timestamp(),
+            cancelled_at: 0,
+            state: StreamState::Active,
+        };
+
+        // Add to whatever allowance is left for the tipper's other streams.
+        let allowance = token::Client::new(&env, &stream.asset)
+            .allowance(&stream.tipper, &env.current_contract_address())
+            .checked_add(total)
             .ok_or(Error::TotalOverflow)?;
-        env.storage()
-            .instance()
-            .set(&DataKey::RecipientTotal(recipient.clone()), &next_total);
+        let expiry = stream_end_ledger(&env, &stream);
+        set_stream_allowance(&env, &stream.asset, &stream.tipper, allowance, expiry);
+
+        env.storage().instance().set(&DataKey::StreamNonce, &id);
+        write_stream(&env, &stream);
+        emit_stream_event(&env, &stream);
+
+        Ok(id)
+    }
+
+    /// Pay out the periods of a stream that have elapsed since the last
+    /// settlement, one settlement receipt per period. Anyone may call this.
+    ///
+    /// At most `MAX_SETTLE_PERIODS` periods are paid per call; any remainder
+    /// stays due. Returns the settlement IDs in order (empty if nothing is due).
+    pub fn settle_due(env: Env, stream_id: u64) -> Result<Vec<u64>, Error> {
+        emergency_pause::assert_not_paused(&env)?;
+
+        let mut stream = read_stream(&env, stream_id)?;
+        let due = stream_periods_due(&env, &stream).min(Self::MAX_SETTLE_PERIODS);
+
+        let mut settlement_ids = Vec::new(&env);
+        for _ in 0..due {
+            let settlement_id = settle(
+                &env,
+                &stream.asset,
+                Funding::Allowance(&stream.tipper),
+                stream.recipient.clone(),
+                stream.amount_per_period,
+                SorobanString::from_str(&env, ""),
+            )?;
+            settlement_ids.push_back(settlement_id);
+        }
+
+        if due > 0 {
+            stream.periods_paid += due;
+            write_stream(&env, &stream);
+        }
+        Ok(settlement_ids)
+    }
+
+    /// Stop a stream. Tipper only.
+    ///
+    /// Periods that elapsed before cancellation stay payable via `settle_due`;
+    /// the allowance reserved for the remaining periods is released.
+    pub fn cancel_stream(env: Env, tipper: Address, stream_id: u64) -> Result<(), Error> {
+        tipper.require_auth();
+
+        let mut stream = read_stream(&env, stream_id)?;
+        if stream.tipper != tipper {
+            return Err(Error::Unauthorized);
+        }
+        if stream.state != StreamState::Active {
+            return Err(Error::StreamNotActive);
+        }
+
+        stream.state = StreamState::Cancelled;
+        stream.cancelled_at = env.ledger().timestamp();
+        let payable = stream.periods_paid + stream_periods_due(&env, &stream);
+        let released = stream.amount_per_period * (stream.max_periods - payable) as i128;
+
+        let allowance = token::Client::new(&env, &stream.asset)
+            .allowance(&tipper, &env.current_contract_address());
+        let remaining = (allowance - released).max(0);
+        set_stream_allowance(&env, &stream.asset, &tipper, remaining, env.ledger().sequence());
+
+        write_stream(&env, &stream);
+        emit_stream_event(&env, &stream);
+        Ok(())
+    }
+
+    /// Get a tip stream by ID.
+    pub fn get_stream(env: Env, stream_id: u64) -> Option<TipStream> {
+        read_stream(&env, stream_id).ok()
+    }
+
+    /// Number of elapsed, unpaid periods currently due on a stream.
+    pub fn get_stream_due(env: Env, stream_id: u64) -> Result<u32, Error> {
+        let stream = read_stream(&env, stream_id)?;
+        Ok(stream_periods_due(&env, &stream))
+    }
+
+    /// Enable escrowed tips, resolving authors through `registry` and letting
+    /// tippers reclaim unclaimed escrows `expiry_secs` after they were opened.
+    ///
+    /// Owner only. Like `init`, the first call fixes the configuration and
+    /// later calls are no-ops.
+    pub fn init_escrow(
+        env: Env,
+        caller: Address,
+        registry: Address,
+        expiry_secs: u64,
+    ) -> Result<(), Error> {
+        access_control::require_owner(&env, &caller)?;
+
+        if env.storage().instance().has(&DataKey::EscrowConfig) {
+            return Ok(());
+        }
+        if expiry_secs == 0 || expiry_secs > Self::MAX_ESCROW_EXPIRY_SECS {
+            return Err(Error::InvalidEscrowExpiry);
+        }
+
+        env.storage().instance().set(
+            &DataKey::EscrowConfig,
+            &EscrowConfig {
+                registry,
+                expiry_secs,
+            },
+        );
+        Ok(())
+    }
+
+    /// Escrow a tip against a confession without learning its author.
+    ///
+    /// Pulls `amount` from `tipper` into the contract and returns the escrow ID.
+    /// The author claims it with `claim_escrow`; after the expiry the tipper
+    /// may reclaim it with `refund_escrow`.
+    pub fn escrow_tip(
+        env: Env,
+        tipper: Address,
+        target: TipTarget,
+        asset: Address,
+        amount: i128,
+    ) -> Result<u64, Error> {
+        tipper.require_auth();
+
+        emergency_pause::assert_not_paused(&env)?;
+        require_accepted(&env, &asset)?;
+        let config = escrow_config(&env)?;
+        check_tip_amount(&env, &asset, amount)?;
+        record_tipper_action(&env, &tipper)?;
 
-        let settlement_id = env
+        let id = env
             .storage()
             .instance()
-            .get::<_, u64>(&DataKey::SettlementNonce)
+            .get::<_, u64>(&DataKey::EscrowNonce)
             .unwrap_or(0_u64)
             .checked_add(1)
             .ok_or(Error::NonceOverflow)?;
-        env.storage()
-            .instance()
-            .set(&DataKey::SettlementNonce, &settlement_id);
 
-        let payload = SettlementReceiptEvent {
-            event_version: EVENT_VERSION_V1,
-            settlement_id,
-            recipient: recipient.clone(),
+        let now = env.ledger().timestamp();
+        let escrow = EscrowedTip {
+            id,
+            tipper: tipper.clone(),
+            target,
+            asset: asset.clone(),
             amount,
-            proof_metadata: metadata.clone(),
-            proof_present: metadata.len() > 0,
-            timestamp: env.ledger().timestamp(),
+            created_at: now,
+            expires_at: now.saturating_add(config.expiry_secs),
+            state: EscrowState::Pending,
         };
-        env.events().publish((SETTLEMENT_EVENT, recipient), payload);
+
+        token::Client::new(&env, &asset).transfer(
+            &tipper,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        env.storage().instance().set(&DataKey::EscrowNonce, &id);
+        write_escrow(&env, &escrow);
+        emit_escrow_event(&env, &escrow, tipper);
+
+        Ok(id)
+    }
+
+    /// Release a pending escrow to the confession author.
+    ///
+    /// `claimant` must authorize the call and match the `author` recorded in
+    /// `ConfessionRegistry` for the escrow's target. Returns the settlement ID
+    /// of the resulting receipt.
+    pub fn claim_escrow(env: Env, claimant: Address, escrow_id: u64) -> Result<u64, Error> {
+        claimant.require_auth();
+
+        emergency_pause::assert_not_paused(&env)?;
+        let config = escrow_config(&env)?;
+        let mut escrow = read_escrow(&env, escrow_id)?;
+        if escrow.state != EscrowState::Pending {
+            return Err(Error::EscrowAlreadySettled);
+        }
+
+        let registry = ConfessionRegistryClient::new(&env, &config.registry);
+        let confession_id = match &escrow.target {
+            TipTarget::Confession(id) => *id,
+            TipTarget::Commitment(hash) => match registry.try_get_by_hash(hash) {
+                Ok(Ok(id)) => id,
+                _ => return Err(Error::ConfessionNotFound),
+            },
+        };
+        let author = match registry.try_get_confession(&confession_id) {
+            Ok(Ok(confession)) => confession.author,
+            _ => return Err(Error::ConfessionNotFound),
+        };
+        if author != claimant {
+            return Err(Error::NotConfessionAuthor);
+        }
+
+        let settlement_id = settle(
+            &env,
+            &escrow.asset,
+            Funding::Held,
+            claimant.clone(),
+            escrow.amount,
+            SorobanString::from_str(&env, ""),
+        )?;
+
+        escrow.state = EscrowState::Claimed;
+        write_escrow(&env, &escrow);
+        emit_escrow_event(&env, &escrow, claimant);
 
         Ok(settlement_id)
     }
 
-    /// Get tip history for a recipient
-    pub fn get_tips(env: Env, recipient: Address) -> i128 {
-        env.storage()
-            .instance()
-            .get::<_, i128>(&DataKey::RecipientTotal(recipient))
-            .unwrap_or(0_i128)
+    /// Return an unclaimed escrow to its tipper once it has expired.
+    pub fn refund_escrow(env: Env, escrow_id: u64) -> Result<(), Error> {
+        let mut escrow = read_escrow(&env, escrow_id)?;
+        escrow.tipper.require_auth();
+
+        emergency_pause::assert_not_paused(&env)?;
+
+        if escrow.state != EscrowState::Pending {
+            return Err(Error::EscrowAlreadySettled);
+        }
+        if env.ledger().timestamp() < escrow.expires_at {
+            return Err(Error::EscrowNotExpired);
+        }
+
+        token::Client::new(&env, &escrow.asset).transfer(
+            &env.current_contract_address(),
+            &escrow.tipper,
+            &escrow.amount,
+        );
+
+        escrow.state = EscrowState::Refunded;
+        write_escrow(&env, &escrow);
+        emit_escrow_event(&env, &escrow, escrow.tipper.clone());
+
+        Ok(())
+    }
+
+    /// Get an escrowed tip by ID.
+    pub fn get_escrow(env: Env, escrow_id: u64) -> Option<EscrowedTip> {
+        read_escrow(&env, escrow_id).ok()
+    }
+
+    /// Current escrow configuration, if escrow has been enabled.
+    pub fn get_escrow_config(env: Env) -> Option<EscrowConfig> {
+        escrow_config(&env).ok()
+    }
+
+    /// Get the running tip total for a recipient in one asset
+    pub fn get_tips(env: Env, recipient: Address, asset: Address) -> i128 {
+        recipient_total(&env, &recipient, &asset)
+    }
+
+    /// Get a recipient's running tip totals keyed by asset.
+    ///
+    /// Includes assets that have since been removed from the allow-list.
+    pub fn get_tips_by_asset(env: Env, recipient: Address) -> Map<Address, i128> {
+        let mut totals = Map::new(&env);
+        let index_key = DataKey::RecipientAssets(recipient.clone());
+        let assets = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<Address>>(&index_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        if !assets.is_empty() {
+            extend_persistent(&env, &index_key);
+        }
+        for asset in assets.iter() {
+            let total = recipient_total(&env, &recipient, &asset);
+            totals.set(asset, total);
+        }
+        totals
+    }
+
+    /// Get the ledger record for a settlement, if it exists.
+    pub fn get_settlement(env: Env, settlement_id: u64) -> Option<SettlementRecord> {
+        read_settlement(&env, settlement_id)
+    }
+
+    /// Get a page of settlements received by `recipient`, oldest first.
+    ///
+    /// - `cursor`: position to resume from (`None` starts at the beginning).
+    /// - `limit`: maximum records returned, `1..=MAX_PAGE_SIZE`.
+    pub fn get_settlements_for_recipient(
+        env: Env,
+        recipient: Address,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> Result<SettlementPage, Error> {
+        history_page(&env, &recipient, cursor, limit)
     }
 
     /// Read helper used by backend indexers/reconciliation workers.
@@ -138,5 +1549,15 @@ impl AnonymousTipping {
     }
 }
 
+#[cfg(test)]
+mod test_support;
 #[cfg(test)]
 mod tipping_adversarial;
+#[cfg(test)]
+mod tipping_escrow;
+#[cfg(test)]
+mod tipping_stream;
+#[cfg(test)]
+mod tipping_limits;
+#[cfg(test)]
+mod tipping_batch;
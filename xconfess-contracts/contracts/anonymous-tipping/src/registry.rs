@@ -0,0 +1,36 @@
+//! Minimal client for the `ConfessionRegistry` contract.
+//!
+//! Mirrors only the parts of the registry ABI the escrow claim flow reads, so
+//! this crate does not need to link the registry contract itself. The mirrored
+//! types must stay field-for-field compatible with the registry's definitions.
+
+use soroban_sdk::{contractclient, contracttype, Address, BytesN, Env};
+
+/// Mirror of `confession_registry::ConfessionStatus`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfessionStatus {
+    Active,
+    Deleted,
+    Flagged,
+    Hidden,
+}
+
+/// Mirror of `confession_registry::Confession`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Confession {
+    pub id: u64,
+    pub author: Address,
+    pub content_hash: BytesN<32>,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub status: ConfessionStatus,
+}
+
+#[allow(dead_code)]
+#[contractclient(name = "ConfessionRegistryClient")]
+pub trait ConfessionRegistry {
+    fn get_confession(env: Env, id: u64) -> Confession;
+    fn get_by_hash(env: Env, content_hash: BytesN<32>) -> u64;
+}
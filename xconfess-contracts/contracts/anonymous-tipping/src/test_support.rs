@@ -0,0 +1,56 @@
+//! Shared fixture for the anonymous-tipping test modules.
+//!
+//! Registers a fresh Stellar asset contract and the tipping contract,
+//! initializes it with `owner` and mints `tipper` the requested balance.
+use core::fmt::Debug;
+
+use soroban_sdk::{
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+use crate::{AnonymousTipping, AnonymousTippingClient, Error};
+
+pub struct Fixture<'a> {
+    pub env: Env,
+    pub tipping: AnonymousTippingClient<'a>,
+    pub token: TokenClient<'a>,
+    pub owner: Address,
+    pub tipper: Address,
+    /// A fresh address with no balance, for tests that need one recipient.
+    pub recipient: Address,
+}
+
+pub fn setup<'a>(tipper_balance: i128) -> Fixture<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let tipping = AnonymousTippingClient::new(&env, &env.register(AnonymousTipping, ()));
+    let owner = Address::generate(&env);
+    tipping.init(&owner, &asset);
+    let tipper = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&tipper, &tipper_balance);
+    Fixture {
+        token: TokenClient::new(&env, &asset),
+        recipient: Address::generate(&env),
+        env,
+        tipping,
+        owner,
+        tipper,
+    }
+}
+
+/// Flatten a `try_*` client call into the contract's own result, panicking
+/// on host or conversion failures.
+pub fn contract_result<T: Debug, C: Debug, I: Debug>(
+    result: Result<Result<T, C>, Result<Error, I>>,
+) -> Result<T, Error> {
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Err(Ok(err)) => Err(err),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
@@ -8,21 +8,40 @@
 mod adversarial {
     extern crate std;
 
-    use soroban_sdk::{testutils::Address as _, Address, Env, String as SorobanString};
+    use soroban_sdk::{
+        testutils::{Address as _, Events as _},
+        token::{StellarAssetClient, TokenClient},
+        vec, Address, Env, IntoVal, String as SorobanString,
+    };
 
     // The #[contractimpl] macro emits `AnonymousTippingClient<'_>` alongside
     // the contract struct at the crate root.
-    use crate::{AnonymousTipping, AnonymousTippingClient, Error};
+    use crate::{
+        AnonymousTipping, AnonymousTippingClient, Error, FeeUpdatedEvent, SettlementReceiptEvent,
+    };
 
     // ── helpers ──────────────────────────────────────────────────────────────
 
-    fn setup() -> (Env, Address) {
+    /// Registers the contract against a fresh Stellar asset contract and
+    /// returns `(env, contract_id, tipper, asset)`, with the tipper funded to
+    /// `i128::MAX`.
+    fn setup() -> (Env, Address, Address, Address) {
         let env = Env::default();
+        env.mock_all_auths();
+        let asset = register_token(&env);
         let contract_id = env.register(AnonymousTipping, ());
-        AnonymousTippingClient::new(&env, &contract_id).init();
-        (env, contract_id)
+        AnonymousTippingClient::new(&env, &contract_id).init(&Address::generate(&env), &asset);
+        let tipper = Address::generate(&env);
+        StellarAssetClient::new(&env, &asset).mint(&tipper, &i128::MAX);
+        (env, contract_id, tipper, asset)
     }
 
+    fn register_token(env: &Env) -> Address {
+        let issuer = Address::generate(env);
+        env.register_stellar_asset_contract_v2(issuer).address()
+    }
+
+
     fn mk_client<'a>(env: &'a Env, id: &'a Address) -> AnonymousTippingClient<'a> {
         AnonymousTippingClient::new(env, id)
     }
@@ -35,10 +54,10 @@ mod adversarial {
 
     #[test]
     fn zero_amount_tip_rejected() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
-        let r = c.try_send_tip(&recipient, &0i128);
+        let r = c.try_send_tip(&tipper, &recipient, &asset, &0i128);
         assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
     }
 
@@ -46,48 +65,48 @@ mod adversarial {
 
     #[test]
     fn negative_one_amount_rejected() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
-        let r = c.try_send_tip(&recipient, &(-1i128));
+        let r = c.try_send_tip(&tipper, &recipient, &asset, &(-1i128));
         assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
     }
 
     #[test]
     fn negative_large_amount_rejected() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
-        let r = c.try_send_tip(&recipient, &(-1_000_000i128));
+        let r = c.try_send_tip(&tipper, &recipient, &asset, &(-1_000_000i128));
         assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
     }
 
     #[test]
     fn i128_min_amount_rejected() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
-        let r = c.try_send_tip(&recipient, &i128::MIN);
+        let r = c.try_send_tip(&tipper, &recipient, &asset, &i128::MIN);
         assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
     }
 
     #[test]
     fn zero_amount_with_proof_rejected() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
         let m = meta(&env, 10);
-        let r = c.try_send_tip_with_proof(&recipient, &0i128, &Some(m.clone()));
+        let r = c.try_send_tip_with_proof(&tipper, &recipient, &asset, &0i128, &Some(m.clone()));
         assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
     }
 
     #[test]
     fn negative_amount_with_proof_rejected() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
         let m = meta(&env, 10);
-        let r = c.try_send_tip_with_proof(&recipient, &(-42i128), &Some(m.clone()));
+        let r = c.try_send_tip_with_proof(&tipper, &recipient, &asset, &(-42i128), &Some(m));
         assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
     }
 
@@ -95,77 +114,77 @@ mod adversarial {
 
     #[test]
     fn metadata_empty_succeeds() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
         let m = meta(&env, 0);
-        let sid = c.send_tip_with_proof(&recipient, &1i128, &Some(m));
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &asset, &1i128, &Some(m));
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 1);
+        assert_eq!(c.get_tips(&recipient, &asset), 1);
     }
 
     #[test]
     fn metadata_64_bytes_succeeds() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
         let m = meta(&env, 64);
-        let sid = c.send_tip_with_proof(&recipient, &5i128, &Some(m));
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &asset, &5i128, &Some(m));
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 5);
+        assert_eq!(c.get_tips(&recipient, &asset), 5);
     }
 
     #[test]
     fn metadata_127_bytes_succeeds() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
         let m = meta(&env, 127);
-        let sid = c.send_tip_with_proof(&recipient, &1i128, &Some(m));
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &asset, &1i128, &Some(m));
         assert_eq!(sid, 1);
     }
 
     #[test]
     fn metadata_exactly_max_succeeds() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
         let m = meta(&env, AnonymousTipping::MAX_PROOF_METADATA_LEN as usize);
-        let sid = c.send_tip_with_proof(&recipient, &7i128, &Some(m));
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &asset, &7i128, &Some(m));
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 7);
+        assert_eq!(c.get_tips(&recipient, &asset), 7);
     }
 
     #[test]
     fn metadata_max_plus_one_rejected() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
         let m = meta(
             &env,
             (AnonymousTipping::MAX_PROOF_METADATA_LEN + 1) as usize,
         );
-        let r = c.try_send_tip_with_proof(&recipient, &1i128, &Some(m.clone()));
+        let r = c.try_send_tip_with_proof(&tipper, &recipient, &asset, &1i128, &Some(m.clone()));
         assert_eq!(r, Err(Ok(Error::MetadataTooLong)));
     }
 
     #[test]
     fn metadata_256_bytes_rejected() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
         let m = meta(&env, 256);
-        let r = c.try_send_tip_with_proof(&recipient, &1i128, &Some(m.clone()));
+        let r = c.try_send_tip_with_proof(&tipper, &recipient, &asset, &1i128, &Some(m.clone()));
         assert_eq!(r, Err(Ok(Error::MetadataTooLong)));
     }
 
     #[test]
     fn metadata_extremely_large_rejected() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
         let m = meta(&env, 1024);
-        let r = c.try_send_tip_with_proof(&recipient, &1i128, &Some(m.clone()));
+        let r = c.try_send_tip_with_proof(&tipper, &recipient, &asset, &1i128, &Some(m.clone()));
         assert_eq!(r, Err(Ok(Error::MetadataTooLong)));
     }
 
@@ -173,14 +192,14 @@ mod adversarial {
 
     #[test]
     fn settlement_ids_are_monotonically_increasing() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let r1 = Address::generate(&env);
         let r2 = Address::generate(&env);
 
-        let id1 = c.send_tip(&r1, &1i128);
-        let id2 = c.send_tip(&r2, &1i128);
-        let id3 = c.send_tip(&r1, &1i128);
+        let id1 = c.send_tip(&tipper, &r1, &asset, &1i128);
+        let id2 = c.send_tip(&tipper, &r2, &asset, &1i128);
+        let id3 = c.send_tip(&tipper, &r1, &asset, &1i128);
 
         assert_eq!(id1, 1);
         assert_eq!(id2, 2);
@@ -192,40 +211,40 @@ mod adversarial {
 
     #[test]
     fn same_recipient_tips_accumulate() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
 
-        c.send_tip(&recipient, &10i128);
-        c.send_tip(&recipient, &25i128);
-        c.send_tip(&recipient, &5i128);
+        c.send_tip(&tipper, &recipient, &asset, &10i128);
+        c.send_tip(&tipper, &recipient, &asset, &25i128);
+        c.send_tip(&tipper, &recipient, &asset, &5i128);
 
-        assert_eq!(c.get_tips(&recipient), 40);
+        assert_eq!(c.get_tips(&recipient, &asset), 40);
     }
 
     #[test]
     fn multiple_recipients_are_independent() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let r1 = Address::generate(&env);
         let r2 = Address::generate(&env);
 
-        c.send_tip(&r1, &100i128);
-        c.send_tip(&r2, &200i128);
+        c.send_tip(&tipper, &r1, &asset, &100i128);
+        c.send_tip(&tipper, &r2, &asset, &200i128);
 
-        assert_eq!(c.get_tips(&r1), 100);
-        assert_eq!(c.get_tips(&r2), 200);
+        assert_eq!(c.get_tips(&r1, &asset), 100);
+        assert_eq!(c.get_tips(&r2, &asset), 200);
     }
 
     // ── volume smoke: 10 sequential tips ─────────────────────────────────────
 
     #[test]
     fn ten_sequential_tips_nonce_matches_count() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         for i in 1_u64..=10 {
             let recipient = Address::generate(&env);
-            let sid = c.send_tip(&recipient, &(i as i128));
+            let sid = c.send_tip(&tipper, &recipient, &asset, &(i as i128));
             assert_eq!(sid, i);
         }
         assert_eq!(c.latest_settlement_nonce(), 10);
@@ -235,14 +254,17 @@ mod adversarial {
 
     #[test]
     fn double_init_is_safe() {
-        let (env, id) = setup(); // already calls init once
+        let (env, id, tipper, asset) = setup(); // already calls init once
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
-        c.send_tip(&recipient, &1i128);
+        c.send_tip(&tipper, &recipient, &asset, &1i128);
 
-        c.init(); // second init must not reset state
+        let assets = c.get_accepted_assets();
+        // second init must not reset state
+        c.init(&Address::generate(&env), &register_token(&env));
 
-        assert_eq!(c.get_tips(&recipient), 1);
+        assert_eq!(c.get_accepted_assets(), assets);
+        assert_eq!(c.get_tips(&recipient, &asset), 1);
         assert_eq!(c.latest_settlement_nonce(), 1);
     }
 
@@ -250,86 +272,141 @@ mod adversarial {
 
     #[test]
     fn send_tip_and_proof_none_produce_equal_totals() {
-        let env = Env::default();
-        let contract_id = env.register(AnonymousTipping, ());
-        let c = mk_client(&env, &contract_id);
-        c.init();
+        let (env, id, tipper, asset) = setup();
+        let c = mk_client(&env, &id);
 
         let r1 = Address::generate(&env);
         let r2 = Address::generate(&env);
 
-        c.send_tip(&r1, &42i128);
-        c.send_tip_with_proof(&r2, &42i128, &None);
+        c.send_tip(&tipper, &r1, &asset, &42i128);
+        c.send_tip_with_proof(&tipper, &r2, &asset, &42i128, &None);
 
-        assert_eq!(c.get_tips(&r1), c.get_tips(&r2));
+        assert_eq!(c.get_tips(&r1, &asset), c.get_tips(&r2, &asset));
     }
 
-    // ── uninitialised contract still works ───────────────────────────────────
+    // ── uninitialised contract has no asset to settle in ─────────────────────
 
     #[test]
-    fn tip_without_explicit_init_still_succeeds() {
+    fn tip_without_init_rejected() {
         let env = Env::default();
+        env.mock_all_auths();
+        let asset = register_token(&env);
         let contract_id = env.register(AnonymousTipping, ());
         let c = mk_client(&env, &contract_id);
-        // No c.init() call — storage defaults to 0 via `unwrap_or`
+        let tipper = Address::generate(&env);
         let recipient = Address::generate(&env);
-        let sid = c.send_tip(&recipient, &3i128);
-        assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 3);
+        let r = c.try_send_tip(&tipper, &recipient, &asset, &3i128);
+        assert_eq!(r, Err(Ok(Error::NotInitialized)));
+        assert_eq!(c.get_tips(&recipient, &asset), 0);
+        assert_eq!(c.latest_settlement_nonce(), 0);
+    }
+
+    // ── token movement ────────────────────────────────────────────────────────
+
+    #[test]
+    fn tip_moves_tokens_from_tipper_to_recipient() {
+        let (env, id, tipper, asset) = setup();
+        let c = mk_client(&env, &id);
+        let token = TokenClient::new(&env, &asset);
+        let recipient = Address::generate(&env);
+
+        c.send_tip(&tipper, &recipient, &asset, &250i128);
+        c.send_tip_with_proof(&tipper, &recipient, &asset, &50i128, &Some(meta(&env, 8)));
+
+        assert_eq!(token.balance(&recipient), 300);
+        assert_eq!(token.balance(&tipper), i128::MAX - 300);
+        assert_eq!(token.balance(&id), 0);
+        assert_eq!(c.get_tips(&recipient, &asset), 300);
+    }
+
+    #[test]
+    fn tip_requires_tipper_auth() {
+        let (env, id, tipper, asset) = setup();
+        let c = mk_client(&env, &id);
+        let recipient = Address::generate(&env);
+
+        c.send_tip(&tipper, &recipient, &asset, &10i128);
+
+        let auths = env.auths();
+        assert_eq!(auths.len(), 1);
+        assert_eq!(auths[0].0, tipper);
+    }
+
+    #[test]
+    #[should_panic]
+    fn tip_without_auth_panics() {
+        let (env, id, tipper, asset) = setup();
+        env.set_auths(&[]);
+        let c = mk_client(&env, &id);
+        let recipient = Address::generate(&env);
+        c.send_tip(&tipper, &recipient, &asset, &10i128);
+    }
+
+    #[test]
+    fn insufficient_balance_leaves_state_untouched() {
+        let (env, id, _, asset) = setup();
+        let c = mk_client(&env, &id);
+        let broke = Address::generate(&env);
+        let recipient = Address::generate(&env);
+
+        assert!(c.try_send_tip(&broke, &recipient, &asset, &10i128).is_err());
+        assert_eq!(c.get_tips(&recipient, &asset), 0);
+        assert_eq!(c.latest_settlement_nonce(), 0);
+        assert_eq!(TokenClient::new(&env, &asset).balance(&recipient), 0);
     }
 
     // ── unknown recipient returns zero ────────────────────────────────────────
 
     #[test]
     fn get_tips_returns_zero_for_unknown_recipient() {
-        let (env, id) = setup();
+        let (env, id, _, asset) = setup();
         let c = mk_client(&env, &id);
         let unknown = Address::generate(&env);
-        assert_eq!(c.get_tips(&unknown), 0);
+        assert_eq!(c.get_tips(&unknown, &asset), 0);
     }
 
     // ── large and small valid amounts ─────────────────────────────────────────
 
     #[test]
     fn very_large_valid_amount_succeeds() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
         let amount: i128 = 1_000_000_000_000;
-        let sid = c.send_tip(&recipient, &amount);
+        let sid = c.send_tip(&tipper, &recipient, &asset, &amount);
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), amount);
+        assert_eq!(c.get_tips(&recipient, &asset), amount);
     }
 
     #[test]
     fn minimum_valid_amount_one_succeeds() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
-        let sid = c.send_tip(&recipient, &1i128);
+        let sid = c.send_tip(&tipper, &recipient, &asset, &1i128);
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 1);
+        assert_eq!(c.get_tips(&recipient, &asset), 1);
     }
 
     // ── overflow edge cases ─────────────────────────────────────────────────────
 
     #[test]
     fn total_overflow_returns_error() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
 
         // Send a tip that brings total to near max
-        c.send_tip(&recipient, &(i128::MAX - 100));
+        c.send_tip(&tipper, &recipient, &asset, &(i128::MAX - 100));
 
         // Next tip should overflow
-        let r = c.try_send_tip(&recipient, &200i128);
+        let r = c.try_send_tip(&tipper, &recipient, &asset, &200i128);
         assert_eq!(r, Err(Ok(Error::TotalOverflow)));
     }
 
     #[test]
     fn nonce_overflow_returns_error() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
 
@@ -341,7 +418,7 @@ mod adversarial {
         });
 
         // Next tip should overflow nonce
-        let r = c.try_send_tip(&recipient, &1i128);
+        let r = c.try_send_tip(&tipper, &recipient, &asset, &1i128);
         assert_eq!(r, Err(Ok(Error::NonceOverflow)));
     }
 
@@ -349,7 +426,7 @@ mod adversarial {
 
     #[test]
     fn metadata_unicode_succeeds() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
 
@@ -357,14 +434,14 @@ mod adversarial {
         let unicode_str = "🚀💰测试🔥";
         let metadata = SorobanString::from_str(&env, unicode_str);
 
-        let sid = c.send_tip_with_proof(&recipient, &5i128, &Some(metadata));
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &asset, &5i128, &Some(metadata));
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 5);
+        assert_eq!(c.get_tips(&recipient, &asset), 5);
     }
 
     #[test]
     fn metadata_whitespace_succeeds() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
 
@@ -372,23 +449,317 @@ mod adversarial {
         let whitespace_str = " \t\n\r ";
         let metadata = SorobanString::from_str(&env, whitespace_str);
 
-        let sid = c.send_tip_with_proof(&recipient, &3i128, &Some(metadata));
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &asset, &3i128, &Some(metadata));
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 3);
+        assert_eq!(c.get_tips(&recipient, &asset), 3);
     }
 
     // ── amount precision tests ───────────────────────────────────────────────────
 
     #[test]
     fn max_valid_amount_succeeds() {
-        let (env, id) = setup();
+        let (env, id, tipper, asset) = setup();
         let c = mk_client(&env, &id);
         let recipient = Address::generate(&env);
 
         // Test with maximum valid amount (less than would cause overflow)
         let max_amount = i128::MAX / 2;
-        let sid = c.send_tip(&recipient, &max_amount);
+        let sid = c.send_tip(&tipper, &recipient, &asset, &max_amount);
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), max_amount);
+        assert_eq!(c.get_tips(&recipient, &asset), max_amount);
+    }
+
+    // ── multi-asset accounting ───────────────────────────────────────────────
+
+    /// Like `setup`, but also accepts and funds a second asset. Returns
+    /// `(env, contract_id, owner, tipper, xlm, usdc)`.
+    fn setup_two_assets() -> (Env, Address, Address, Address, Address, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let xlm = register_token(&env);
+        let usdc = register_token(&env);
+        let owner = Address::generate(&env);
+        let contract_id = env.register(AnonymousTipping, ());
+        let c = mk_client(&env, &contract_id);
+        c.init(&owner, &xlm);
+        c.add_accepted_asset(&owner, &usdc);
+        let tipper = Address::generate(&env);
+        StellarAssetClient::new(&env, &xlm).mint(&tipper, &1_000);
+        StellarAssetClient::new(&env, &usdc).mint(&tipper, &1_000);
+        (env, contract_id, owner, tipper, xlm, usdc)
+    }
+
+    #[test]
+    fn totals_are_tracked_per_asset() {
+        let (env, id, _, tipper, xlm, usdc) = setup_two_assets();
+        let c = mk_client(&env, &id);
+        let recipient = Address::generate(&env);
+
+        c.send_tip(&tipper, &recipient, &xlm, &10i128);
+        c.send_tip(&tipper, &recipient, &usdc, &3i128);
+        c.send_tip(&tipper, &recipient, &xlm, &5i128);
+
+        assert_eq!(c.get_tips(&recipient, &xlm), 15);
+        assert_eq!(c.get_tips(&recipient, &usdc), 3);
+        assert_eq!(TokenClient::new(&env, &usdc).balance(&recipient), 3);
+
+        let by_asset = c.get_tips_by_asset(&recipient);
+        assert_eq!(by_asset.len(), 2);
+        assert_eq!(by_asset.get(xlm).unwrap(), 15);
+        assert_eq!(by_asset.get(usdc).unwrap(), 3);
+    }
+
+    #[test]
+    fn tips_by_asset_empty_for_unknown_recipient() {
+        let (env, id, _, _) = setup();
+        let c = mk_client(&env, &id);
+        assert!(c.get_tips_by_asset(&Address::generate(&env)).is_empty());
+    }
+
+    #[test]
+    fn settlement_event_carries_asset() {
+        let (env, id, _, tipper, _, usdc) = setup_two_assets();
+        let c = mk_client(&env, &id);
+        let recipient = Address::generate(&env);
+
+        c.send_tip(&tipper, &recipient, &usdc, &7i128);
+
+        let events = env.events().all();
+        let (_, _, data) = events.get(events.len() - 1).unwrap();
+        let payload: SettlementReceiptEvent = data.into_val(&env);
+        assert_eq!(payload.event_version, 3);
+        assert_eq!(payload.asset, usdc);
+        assert_eq!(payload.amount, 7);
+    }
+
+    #[test]
+    fn unlisted_asset_rejected() {
+        let (env, id, tipper, _) = setup();
+        let c = mk_client(&env, &id);
+        let other = register_token(&env);
+        StellarAssetClient::new(&env, &other).mint(&tipper, &100);
+
+        let r = c.try_send_tip(&tipper, &Address::generate(&env), &other, &1i128);
+        assert_eq!(r, Err(Ok(Error::AssetNotAccepted)));
+    }
+
+    #[test]
+    fn removed_asset_rejected_but_totals_kept() {
+        let (env, id, owner, tipper, xlm, usdc) = setup_two_assets();
+        let c = mk_client(&env, &id);
+        let recipient = Address::generate(&env);
+        c.send_tip(&tipper, &recipient, &usdc, &4i128);
+
+        c.remove_accepted_asset(&owner, &usdc);
+
+        assert_eq!(c.get_accepted_assets(), vec![&env, xlm]);
+        let r = c.try_send_tip(&tipper, &recipient, &usdc, &1i128);
+        assert_eq!(r, Err(Ok(Error::AssetNotAccepted)));
+        assert_eq!(c.get_tips_by_asset(&recipient).get(usdc.clone()).unwrap(), 4);
+        assert_eq!(
+            c.try_remove_accepted_asset(&owner, &usdc),
+            Err(Ok(Error::AssetNotAccepted))
+        );
+    }
+
+    #[test]
+    fn only_admin_or_owner_manages_assets() {
+        let (env, id, _, _) = setup();
+        let c = mk_client(&env, &id);
+        let stranger = Address::generate(&env);
+        let r = c.try_add_accepted_asset(&stranger, &register_token(&env));
+        assert_eq!(r, Err(Ok(Error::Unauthorized)));
+    }
+
+    // ── platform fee and treasury ─────────────────────────────────────────────
+
+    #[test]
+    fn fee_is_split_into_treasury() {
+        let (env, id, owner, tipper, xlm, _) = setup_two_assets();
+        let c = mk_client(&env, &id);
+        let token = TokenClient::new(&env, &xlm);
+        let recipient = Address::generate(&env);
+
+        c.set_fee_bps(&owner, &250);
+        c.send_tip(&tipper, &recipient, &xlm, &400i128);
+
+        let events = env.events().all();
+        let (_, _, data) = events.get(events.len() - 1).unwrap();
+        let payload: SettlementReceiptEvent = data.into_val(&env);
+        assert_eq!(payload.event_version, 3);
+        assert_eq!(payload.gross_amount, 400);
+        assert_eq!(payload.fee_amount, 10);
+        assert_eq!(payload.amount, 390);
+
+        assert_eq!(token.balance(&recipient), 390);
+        assert_eq!(token.balance(&id), 10);
+        assert_eq!(token.balance(&tipper), 600);
+        assert_eq!(c.get_tips(&recipient, &xlm), 390);
+        assert_eq!(c.get_treasury(&xlm), 10);
+    }
+
+    #[test]
+    fn fee_rounds_down_on_small_tips() {
+        let (env, id, owner, tipper, xlm, _) = setup_two_assets();
+        let c = mk_client(&env, &id);
+        let recipient = Address::generate(&env);
+
+        c.set_fee_bps(&owner, &1_000);
+        c.send_tip(&tipper, &recipient, &xlm, &9i128);
+
+        assert_eq!(c.get_tips(&recipient, &xlm), 9);
+        assert_eq!(c.get_treasury(&xlm), 0);
+    }
+
+    #[test]
+    fn fee_does_not_overflow_on_huge_tips() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let asset = register_token(&env);
+        let owner = Address::generate(&env);
+        let contract_id = env.register(AnonymousTipping, ());
+        let c = mk_client(&env, &contract_id);
+        c.init(&owner, &asset);
+        let tipper = Address::generate(&env);
+        StellarAssetClient::new(&env, &asset).mint(&tipper, &i128::MAX);
+
+        c.set_fee_bps(&owner, &AnonymousTipping::MAX_FEE_BPS);
+        let recipient = Address::generate(&env);
+        c.send_tip(&tipper, &recipient, &asset, &i128::MAX);
+
+        let fee = i128::MAX / 10;
+        assert_eq!(c.get_treasury(&asset), fee);
+        assert_eq!(c.get_tips(&recipient, &asset), i128::MAX - fee);
+    }
+
+    #[test]
+    fn fee_above_cap_rejected_and_change_emits_event() {
+        let (env, id, owner, _, _, _) = setup_two_assets();
+        let c = mk_client(&env, &id);
+
+        let r = c.try_set_fee_bps(&owner, &(AnonymousTipping::MAX_FEE_BPS + 1));
+        assert_eq!(r, Err(Ok(Error::InvalidFee)));
+
+        c.set_fee_bps(&owner, &100);
+        let events = env.events().all();
+        let (_, _, data) = events.get(events.len() - 1).unwrap();
+        let payload: FeeUpdatedEvent = data.into_val(&env);
+        assert_eq!(payload.old_fee_bps, 0);
+        assert_eq!(payload.new_fee_bps, 100);
+        assert_eq!(payload.actor, owner);
+        assert_eq!(c.get_fee_bps(), 100);
+    }
+
+    #[test]
+    fn only_owner_sets_fee_and_withdraws() {
+        let (env, id, _, _, xlm, _) = setup_two_assets();
+        let c = mk_client(&env, &id);
+        let admin = Address::generate(&env);
+        env.as_contract(&id, || {
+            crate::access_control::internal_grant_admin(&env, &admin).unwrap();
+        });
+
+        assert_eq!(c.try_set_fee_bps(&admin, &10), Err(Ok(Error::Unauthorized)));
+        assert_eq!(
+            c.try_withdraw_treasury(&admin, &xlm, &admin, &1),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn owner_withdraws_treasury() {
+        let (env, id, owner, tipper, xlm, _) = setup_two_assets();
+        let c = mk_client(&env, &id);
+        let token = TokenClient::new(&env, &xlm);
+        let vault = Address::generate(&env);
+
+        c.set_fee_bps(&owner, &1_000);
+        c.send_tip(&tipper, &Address::generate(&env), &xlm, &500i128);
+        assert_eq!(c.get_treasury(&xlm), 50);
+
+        assert_eq!(
+            c.try_withdraw_treasury(&owner, &xlm, &vault, &51),
+            Err(Ok(Error::InsufficientTreasury))
+        );
+        c.withdraw_treasury(&owner, &xlm, &vault, &30);
+
+        assert_eq!(token.balance(&vault), 30);
+        assert_eq!(c.get_treasury(&xlm), 20);
+        assert_eq!(token.balance(&id), 20);
+    }
+
+    // ── settlement ledger ─────────────────────────────────────────────────────
+
+    #[test]
+    fn settlement_record_is_stored() {
+        let (env, id, owner, tipper, xlm, _) = setup_two_assets();
+        let c = mk_client(&env, &id);
+        let recipient = Address::generate(&env);
+
+        c.set_fee_bps(&owner, &1_000);
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &xlm, &100i128, &Some(meta(&env, 4)));
+
+        let record = c.get_settlement(&sid).unwrap();
+        assert_eq!(record.settlement_id, sid);
+        assert_eq!(record.recipient, recipient);
+        assert_eq!(record.amount, 90);
+        assert_eq!(record.asset, xlm);
+        assert_eq!(record.timestamp, env.ledger().timestamp());
+        assert!(record.proof_present);
+        assert_eq!(c.get_settlement(&(sid + 1)), None);
+    }
+
+    #[test]
+    fn recipient_history_pages_across_chunks() {
+        let (env, id, tipper, asset) = setup();
+        let c = mk_client(&env, &id);
+        let recipient = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        for i in 1..=60i128 {
+            c.send_tip(&tipper, &recipient, &asset, &i);
+            if i % 20 == 0 {
+                c.send_tip(&tipper, &other, &asset, &1i128);
+            }
+        }
+
+        let mut amounts = std::vec::Vec::new();
+        let mut cursor = None;
+        let mut pages = 0;
+        loop {
+            let page = c.get_settlements_for_recipient(&recipient, &cursor, &25);
+            for record in page.items.iter() {
+                assert_eq!(record.recipient, recipient);
+                amounts.push(record.amount);
+            }
+            pages += 1;
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(pages, 3);
+        assert_eq!(amounts, (1..=60i128).collect::<std::vec::Vec<_>>());
+        let other_page = c.get_settlements_for_recipient(&other, &None, &50);
+        assert_eq!(other_page.items.len(), 3);
+        assert_eq!(other_page.next_cursor, None);
+    }
+
+    #[test]
+    fn history_page_limit_is_bounded() {
+        let (env, id, _, _) = setup();
+        let c = mk_client(&env, &id);
+        let recipient = Address::generate(&env);
+
+        let r = c.try_get_settlements_for_recipient(&recipient, &None, &0);
+        assert_eq!(r, Err(Ok(Error::InvalidPageLimit)));
+        let limit = AnonymousTipping::MAX_PAGE_SIZE + 1;
+        let r = c.try_get_settlements_for_recipient(&recipient, &None, &limit);
+        assert_eq!(r, Err(Ok(Error::InvalidPageLimit)));
+
+        let page = c.get_settlements_for_recipient(&recipient, &None, &10);
+        assert!(page.items.is_empty());
+        assert_eq!(page.next_cursor, None);
     }
 }
@@ -0,0 +1,160 @@
+/// Batch tipping tests for the anonymous-tipping contract.
+#[cfg(test)]
+mod batch {
+    use soroban_sdk::{
+        testutils::{Address as _, Events as _},
+        vec, Address, IntoVal, Symbol, Vec,
+    };
+
+    use crate::test_support::{self, contract_result, Fixture};
+    use crate::{AnonymousTipping, Error, SettlementReceiptEvent, TipLimits};
+
+    fn setup<'a>() -> Fixture<'a> {
+        test_support::setup(1_000)
+    }
+
+    fn send(f: &Fixture, tips: &Vec<(Address, i128)>) -> Result<Vec<u64>, Error> {
+        contract_result(f.tipping.try_send_tips_batch(&f.tipper, &f.token.address, tips))
+    }
+
+    #[test]
+    fn batch_settles_every_entry_in_order() {
+        let f = setup();
+        let a = Address::generate(&f.env);
+        let b = Address::generate(&f.env);
+        f.tipping.send_tip(&f.tipper, &a, &f.token.address, &1);
+
+        let tips = vec![&f.env, (a.clone(), 10_i128), (b.clone(), 20), (a.clone(), 30)];
+        let ids = f.tipping.send_tips_batch(&f.tipper, &f.token.address, &tips);
+
+        assert_eq!(ids, vec![&f.env, 2_u64, 3, 4]);
+        assert_eq!(f.tipping.latest_settlement_nonce(), 4);
+        assert_eq!(f.token.balance(&a), 41);
+        assert_eq!(f.token.balance(&b), 20);
+        assert_eq!(f.token.balance(&f.tipper), 939);
+        assert_eq!(f.tipping.get_tips(&a, &f.token.address), 41);
+        assert_eq!(f.tipping.get_settlement(&3).unwrap().recipient, b);
+        let page = f.tipping.get_settlements_for_recipient(&a, &None, &10);
+        assert_eq!(page.items.len(), 3);
+    }
+
+    #[test]
+    fn batch_uses_a_single_tipper_auth() {
+        let f = setup();
+        let tips = vec![
+            &f.env,
+            (Address::generate(&f.env), 5_i128),
+            (Address::generate(&f.env), 5),
+        ];
+        f.tipping.send_tips_batch(&f.tipper, &f.token.address, &tips);
+
+        let auths = f.env.auths();
+        assert_eq!(auths.len(), 1);
+        assert_eq!(auths[0].0, f.tipper);
+    }
+
+    #[test]
+    fn batch_emits_one_receipt_per_entry() {
+        let f = setup();
+        let a = Address::generate(&f.env);
+        let b = Address::generate(&f.env);
+        let tips = vec![&f.env, (a.clone(), 10_i128), (b.clone(), 20)];
+        f.tipping.send_tips_batch(&f.tipper, &f.token.address, &tips);
+
+        let topic: soroban_sdk::Val = Symbol::new(&f.env, "tip_settl").into_val(&f.env);
+        let mut receipts = Vec::<SettlementReceiptEvent>::new(&f.env);
+        for (contract, topics, data) in f.env.events().all().iter() {
+            if contract == f.tipping.address && topics.get(0).unwrap().shallow_eq(&topic) {
+                receipts.push_back(data.into_val(&f.env));
+            }
+        }
+        assert_eq!(receipts.len(), 2);
+        let first = receipts.get(0).unwrap();
+        assert_eq!(first.event_version, 3);
+        assert_eq!((first.settlement_id, first.recipient, first.amount), (1, a, 10));
+        let second = receipts.get(1).unwrap();
+        assert_eq!((second.settlement_id, second.recipient, second.amount), (2, b, 20));
+    }
+
+    #[test]
+    fn empty_and_oversized_batches_rejected() {
+        let f = setup();
+        assert_eq!(send(&f, &Vec::new(&f.env)), Err(Error::InvalidBatchSize));
+
+        let mut tips = Vec::new(&f.env);
+        for _ in 0..=AnonymousTipping::MAX_BATCH_SIZE {
+            tips.push_back((Address::generate(&f.env), 1_i128));
+        }
+        assert_eq!(send(&f, &tips), Err(Error::InvalidBatchSize));
+        assert_eq!(f.tipping.latest_settlement_nonce(), 0);
+    }
+
+    #[test]
+    fn invalid_entry_rejects_whole_batch() {
+        let f = setup();
+        let a = Address::generate(&f.env);
+        let tips = vec![&f.env, (a.clone(), 10_i128), (a.clone(), 0)];
+
+        assert_eq!(send(&f, &tips), Err(Error::InvalidTipAmount));
+        assert_eq!(f.token.balance(&f.tipper), 1_000);
+        assert_eq!(f.tipping.get_tips(&a, &f.token.address), 0);
+        assert_eq!(f.tipping.latest_settlement_nonce(), 0);
+    }
+
+    #[test]
+    fn aggregate_overflow_rejected() {
+        let f = setup();
+        let a = Address::generate(&f.env);
+        let tips = vec![&f.env, (a.clone(), i128::MAX), (a, 1_i128)];
+        assert_eq!(send(&f, &tips), Err(Error::TotalOverflow));
+    }
+
+    #[test]
+    fn failure_on_later_entry_reverts_earlier_transfers() {
+        let f = setup();
+        let a = Address::generate(&f.env);
+        let limits = TipLimits {
+            min_amount: 0,
+            max_amount: 0,
+            recipient_daily_cap: 50,
+        };
+        f.tipping.set_tip_limits(&f.owner, &f.token.address, &limits);
+
+        let b = Address::generate(&f.env);
+        let tips = vec![&f.env, (b.clone(), 10_i128), (a.clone(), 40), (a.clone(), 20)];
+        assert_eq!(send(&f, &tips), Err(Error::DailyCapExceeded));
+
+        assert_eq!(f.token.balance(&f.tipper), 1_000);
+        assert_eq!(f.token.balance(&b), 0);
+        assert_eq!(f.tipping.get_tips(&a, &f.token.address), 0);
+        assert_eq!(f.tipping.latest_settlement_nonce(), 0);
+
+        // The reverted batch did not consume any of today's cap.
+        f.tipping.send_tip(&f.tipper, &a, &f.token.address, &50);
+    }
+
+    #[test]
+    fn batch_total_checked_against_balance_up_front() {
+        let f = setup();
+        let a = Address::generate(&f.env);
+        let b = Address::generate(&f.env);
+
+        let tips = vec![&f.env, (a.clone(), 600_i128), (b.clone(), 401)];
+        assert_eq!(send(&f, &tips), Err(Error::InsufficientBalance));
+        assert_eq!(f.token.balance(&f.tipper), 1_000);
+        assert_eq!(f.tipping.latest_settlement_nonce(), 0);
+
+        let tips = vec![&f.env, (a.clone(), 600_i128), (b.clone(), 400)];
+        assert_eq!(send(&f, &tips).unwrap().len(), 2);
+        assert_eq!(f.token.balance(&f.tipper), 0);
+    }
+
+    #[test]
+    fn batch_blocked_while_paused() {
+        let f = setup();
+        let reason = soroban_sdk::String::from_str(&f.env, "incident");
+        f.tipping.pause(&f.owner, &reason);
+        let tips = vec![&f.env, (Address::generate(&f.env), 1_i128)];
+        assert_eq!(send(&f, &tips), Err(Error::ContractPaused));
+    }
+}
@@ -0,0 +1,287 @@
+/// Escrow and claim-flow tests for the anonymous-tipping contract.
+///
+/// Registers a real `ConfessionRegistry` alongside the tipping contract so
+/// claims resolve authors through the same cross-contract call used on-chain.
+#[cfg(test)]
+mod escrow {
+    use confession_registry::{ConfessionRegistry, ConfessionRegistryClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Events as _, Ledger as _},
+        token::TokenClient,
+        vec, Address, BytesN, Env, IntoVal, Symbol,
+    };
+
+    use crate::{
+        test_support, AnonymousTipping, AnonymousTippingClient, Error, EscrowSettlementEvent,
+        EscrowState, TipTarget,
+    };
+
+    const EXPIRY: u64 = 7 * 24 * 60 * 60;
+
+    struct Fixture<'a> {
+        env: Env,
+        tipping: AnonymousTippingClient<'a>,
+        registry: ConfessionRegistryClient<'a>,
+        owner: Address,
+        token: TokenClient<'a>,
+        tipper: Address,
+        author: Address,
+        confession_id: u64,
+        content_hash: BytesN<32>,
+    }
+
+    fn setup<'a>() -> Fixture<'a> {
+        let test_support::Fixture {
+            env,
+            tipping,
+            token,
+            owner,
+            tipper,
+            ..
+        } = test_support::setup(1_000);
+
+        let registry_id = env.register(ConfessionRegistry, ());
+        let registry = ConfessionRegistryClient::new(&env, &registry_id);
+        registry.initialize(&Address::generate(&env));
+        let author = Address::generate(&env);
+        let content_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let confession_id = registry.create_confession(&author, &content_hash, &1);
+        tipping.init_escrow(&owner, &registry_id, &EXPIRY);
+
+        Fixture {
+            token,
+            env,
+            tipping,
+            registry,
+            owner,
+            tipper,
+            author,
+            confession_id,
+            content_hash,
+        }
+    }
+
+    fn escrow(f: &Fixture, target: TipTarget, amount: i128) -> u64 {
+        f.tipping
+            .escrow_tip(&f.tipper, &target, &f.token.address, &amount)
+    }
+
+    fn advance(env: &Env, secs: u64) {
+        env.ledger().with_mut(|li| li.timestamp += secs);
+    }
+
+    #[test]
+    fn escrow_holds_funds_in_contract() {
+        let f = setup();
+        let id = escrow(&f, TipTarget::Confession(f.confession_id), 100);
+
+        assert_eq!(id, 1);
+        assert_eq!(f.token.balance(&f.tipper), 900);
+        assert_eq!(f.token.balance(&f.tipping.address), 100);
+        assert_eq!(f.tipping.get_tips(&f.author, &f.token.address), 0);
+
+        let escrow = f.tipping.get_escrow(&id).unwrap();
+        assert_eq!(escrow.state, EscrowState::Pending);
+        assert_eq!(escrow.expires_at, escrow.created_at + EXPIRY);
+    }
+
+    #[test]
+    fn author_claims_by_confession_id() {
+        let f = setup();
+        let id = escrow(&f, TipTarget::Confession(f.confession_id), 100);
+
+        let settlement_id = f.tipping.claim_escrow(&f.author, &id);
+
+        assert_eq!(settlement_id, 1);
+        assert_eq!(f.token.balance(&f.author), 100);
+        assert_eq!(f.token.balance(&f.tipping.address), 0);
+        assert_eq!(f.tipping.get_tips(&f.author, &f.token.address), 100);
+        assert_eq!(f.tipping.latest_settlement_nonce(), 1);
+        assert_eq!(f.tipping.get_escrow(&id).unwrap().state, EscrowState::Claimed);
+
+        let record = f.tipping.get_settlement(&settlement_id).unwrap();
+        assert_eq!(record.recipient, f.author);
+        let page = f.tipping.get_settlements_for_recipient(&f.author, &None, &10);
+        assert_eq!(page.items, vec![&f.env, record]);
+    }
+
+    #[test]
+    fn author_claims_by_commitment_hash() {
+        let f = setup();
+        let id = escrow(&f, TipTarget::Commitment(f.content_hash.clone()), 40);
+
+        f.tipping.claim_escrow(&f.author, &id);
+        assert_eq!(f.token.balance(&f.author), 40);
+    }
+
+    #[test]
+    fn claim_emits_receipt_and_escrow_event() {
+        let f = setup();
+        let id = escrow(&f, TipTarget::Confession(f.confession_id), 100);
+        f.tipping.claim_escrow(&f.author, &id);
+
+        let events = f.env.events().all();
+        let (_, topics, data) = events.get(events.len() - 1).unwrap();
+        let topic: Symbol = topics.get(0).unwrap().into_val(&f.env);
+        assert_eq!(topic, Symbol::new(&f.env, "tip_escrw"));
+        let payload: EscrowSettlementEvent = data.into_val(&f.env);
+        assert_eq!(payload.escrow_id, id);
+        assert_eq!(payload.state, EscrowState::Claimed);
+        assert_eq!(payload.counterparty, f.author);
+
+        let receipt_topics = events
+            .iter()
+            .filter(|(_, topics, _)| {
+                let t: Symbol = topics.get(0).unwrap().into_val(&f.env);
+                t == Symbol::new(&f.env, "tip_settl")
+            })
+            .count();
+        assert_eq!(receipt_topics, 1);
+    }
+
+    #[test]
+    fn non_author_cannot_claim() {
+        let f = setup();
+        let id = escrow(&f, TipTarget::Confession(f.confession_id), 100);
+        let stranger = Address::generate(&f.env);
+
+        let r = f.tipping.try_claim_escrow(&stranger, &id);
+        assert_eq!(r, Err(Ok(Error::NotConfessionAuthor)));
+        assert_eq!(f.token.balance(&f.tipping.address), 100);
+    }
+
+    #[test]
+    fn claim_for_unknown_confession_rejected() {
+        let f = setup();
+        let id = escrow(&f, TipTarget::Confession(999), 10);
+        let r = f.tipping.try_claim_escrow(&f.author, &id);
+        assert_eq!(r, Err(Ok(Error::ConfessionNotFound)));
+
+        let hash = BytesN::from_array(&f.env, &[9u8; 32]);
+        let id = escrow(&f, TipTarget::Commitment(hash), 10);
+        let r = f.tipping.try_claim_escrow(&f.author, &id);
+        assert_eq!(r, Err(Ok(Error::ConfessionNotFound)));
+    }
+
+    #[test]
+    fn double_claim_rejected() {
+        let f = setup();
+        let id = escrow(&f, TipTarget::Confession(f.confession_id), 100);
+        f.tipping.claim_escrow(&f.author, &id);
+
+        let r = f.tipping.try_claim_escrow(&f.author, &id);
+        assert_eq!(r, Err(Ok(Error::EscrowAlreadySettled)));
+    }
+
+    #[test]
+    fn refund_before_expiry_rejected() {
+        let f = setup();
+        let id = escrow(&f, TipTarget::Confession(f.confession_id), 100);
+        advance(&f.env, EXPIRY - 1);
+
+        let r = f.tipping.try_refund_escrow(&id);
+        assert_eq!(r, Err(Ok(Error::EscrowNotExpired)));
+    }
+
+    #[test]
+    fn refund_after_expiry_returns_funds() {
+        let f = setup();
+        let id = escrow(&f, TipTarget::Confession(f.confession_id), 100);
+        advance(&f.env, EXPIRY);
+
+        f.tipping.refund_escrow(&id);
+        let events = f.env.events().all();
+        let (_, _, data) = events.get(events.len() - 1).unwrap();
+        let payload: EscrowSettlementEvent = data.into_val(&f.env);
+        assert_eq!(payload.state, EscrowState::Refunded);
+        assert_eq!(payload.counterparty, f.tipper);
+
+        assert_eq!(f.token.balance(&f.tipper), 1_000);
+        assert_eq!(f.tipping.get_escrow(&id).unwrap().state, EscrowState::Refunded);
+        assert_eq!(f.tipping.latest_settlement_nonce(), 0);
+
+        let r = f.tipping.try_claim_escrow(&f.author, &id);
+        assert_eq!(r, Err(Ok(Error::EscrowAlreadySettled)));
+    }
+
+    #[test]
+    fn claimed_escrow_cannot_be_refunded() {
+        let f = setup();
+        let id = escrow(&f, TipTarget::Confession(f.confession_id), 100);
+        f.tipping.claim_escrow(&f.author, &id);
+        advance(&f.env, EXPIRY);
+
+        let r = f.tipping.try_refund_escrow(&id);
+        assert_eq!(r, Err(Ok(Error::EscrowAlreadySettled)));
+    }
+
+    #[test]
+    fn escrow_requires_configuration() {
+        let f = test_support::setup(1_000);
+        let target = TipTarget::Confession(1);
+        let r = f
+            .tipping
+            .try_escrow_tip(&f.tipper, &target, &f.token.address, &1);
+        assert_eq!(r, Err(Ok(Error::NotInitialized)));
+
+        let registry = Address::generate(&f.env);
+        assert_eq!(
+            f.tipping.try_init_escrow(&f.owner, &registry, &0),
+            Err(Ok(Error::InvalidEscrowExpiry))
+        );
+        assert_eq!(
+            f.tipping.try_init_escrow(
+                &f.owner,
+                &registry,
+                &(AnonymousTipping::MAX_ESCROW_EXPIRY_SECS + 1)
+            ),
+            Err(Ok(Error::InvalidEscrowExpiry))
+        );
+    }
+
+    #[test]
+    fn init_escrow_is_fixed_after_first_call() {
+        let f = setup();
+        let before = f.tipping.get_escrow_config().unwrap();
+        f.tipping
+            .init_escrow(&f.owner, &Address::generate(&f.env), &1);
+        assert_eq!(f.tipping.get_escrow_config().unwrap(), before);
+        assert_eq!(before.registry, f.registry.address);
+    }
+
+    #[test]
+    fn init_escrow_requires_owner() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let tipping = AnonymousTippingClient::new(&env, &env.register(AnonymousTipping, ()));
+        let registry = Address::generate(&env);
+        let attacker = Address::generate(&env);
+
+        // Before `init` there is no owner to authorize the call.
+        assert_eq!(
+            tipping.try_init_escrow(&attacker, &registry, &EXPIRY),
+            Err(Ok(Error::NotInitialized))
+        );
+
+        tipping.init(&Address::generate(&env), &token_id);
+        assert_eq!(
+            tipping.try_init_escrow(&attacker, &registry, &EXPIRY),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(tipping.get_escrow_config(), None);
+    }
+
+    #[test]
+    fn invalid_escrow_amount_rejected() {
+        let f = setup();
+        let target = TipTarget::Confession(f.confession_id);
+        let r = f
+            .tipping
+            .try_escrow_tip(&f.tipper, &target, &f.token.address, &0);
+        assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
+        assert_eq!(f.tipping.get_escrow(&1), None);
+    }
+}
@@ -0,0 +1,169 @@
+/// Tip limit, rate limit and pause tests for the anonymous-tipping contract.
+#[cfg(test)]
+mod limits {
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger as _},
+        token::StellarAssetClient,
+        Address, Env, String as SorobanString,
+    };
+
+    use crate::test_support::{self, contract_result, Fixture};
+    use crate::{Error, RateLimit, TipLimits};
+
+    fn setup<'a>() -> Fixture<'a> {
+        test_support::setup(1_000_000)
+    }
+
+    fn limits(min_amount: i128, max_amount: i128, recipient_daily_cap: i128) -> TipLimits {
+        TipLimits {
+            min_amount,
+            max_amount,
+            recipient_daily_cap,
+        }
+    }
+
+    fn tip(f: &Fixture, recipient: &Address, amount: i128) -> Result<u64, Error> {
+        contract_result(f.tipping.try_send_tip(&f.tipper, recipient, &f.token.address, &amount))
+    }
+
+    fn reason(env: &Env) -> SorobanString {
+        SorobanString::from_str(env, "incident")
+    }
+
+    #[test]
+    fn min_and_max_tip_enforced() {
+        let f = setup();
+        let recipient = Address::generate(&f.env);
+        f.tipping.set_tip_limits(&f.owner, &f.token.address, &limits(10, 100, 0));
+
+        assert_eq!(tip(&f, &recipient, 9), Err(Error::BelowMinTip));
+        assert_eq!(tip(&f, &recipient, 101), Err(Error::AboveMaxTip));
+        assert!(tip(&f, &recipient, 10).is_ok());
+        assert!(tip(&f, &recipient, 100).is_ok());
+        assert_eq!(f.tipping.get_tips(&recipient, &f.token.address), 110);
+    }
+
+    #[test]
+    fn limits_also_bound_streams() {
+        let f = setup();
+        f.tipping.set_tip_limits(&f.owner, &f.token.address, &limits(10, 0, 0));
+
+        let recipient = Address::generate(&f.env);
+        let r = f
+            .tipping
+            .try_create_tip_stream(&f.tipper, &recipient, &f.token.address, &5, &60, &3);
+        assert_eq!(r, Err(Ok(Error::BelowMinTip)));
+    }
+
+    #[test]
+    fn recipient_daily_cap_resets_next_day() {
+        let f = setup();
+        let recipient = Address::generate(&f.env);
+        let other = Address::generate(&f.env);
+        f.tipping.set_tip_limits(&f.owner, &f.token.address, &limits(0, 0, 100));
+
+        assert!(tip(&f, &recipient, 60).is_ok());
+        assert!(tip(&f, &recipient, 40).is_ok());
+        assert_eq!(tip(&f, &recipient, 1), Err(Error::DailyCapExceeded));
+        assert!(tip(&f, &other, 100).is_ok());
+
+        f.env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+        assert!(tip(&f, &recipient, 100).is_ok());
+    }
+
+    #[test]
+    fn tipper_rate_limit_over_window() {
+        let f = setup();
+        let recipient = Address::generate(&f.env);
+        let rate = RateLimit {
+            max_tips: 2,
+            window_secs: 60,
+        };
+        f.tipping.set_rate_limit(&f.owner, &rate);
+
+        assert!(tip(&f, &recipient, 1).is_ok());
+        assert!(tip(&f, &recipient, 1).is_ok());
+        assert_eq!(tip(&f, &recipient, 1), Err(Error::RateLimited));
+
+        // Another tipper has their own window.
+        let other = Address::generate(&f.env);
+        StellarAssetClient::new(&f.env, &f.token.address).mint(&other, &10);
+        f.tipping.send_tip(&other, &recipient, &f.token.address, &1);
+
+        f.env.ledger().with_mut(|li| li.timestamp += 60);
+        assert!(tip(&f, &recipient, 1).is_ok());
+        assert_eq!(f.tipping.get_rate_limit(), rate);
+    }
+
+    #[test]
+    fn invalid_limits_rejected() {
+        let f = setup();
+        let r = f
+            .tipping
+            .try_set_tip_limits(&f.owner, &f.token.address, &limits(10, 5, 0));
+        assert_eq!(r, Err(Ok(Error::InvalidLimits)));
+        let r = f
+            .tipping
+            .try_set_tip_limits(&f.owner, &f.token.address, &limits(-1, 0, 0));
+        assert_eq!(r, Err(Ok(Error::InvalidLimits)));
+        let rate = RateLimit {
+            max_tips: 1,
+            window_secs: 0,
+        };
+        let r = f.tipping.try_set_rate_limit(&f.owner, &rate);
+        assert_eq!(r, Err(Ok(Error::InvalidLimits)));
+    }
+
+    #[test]
+    fn limits_are_admin_managed() {
+        let f = setup();
+        let admin = Address::generate(&f.env);
+        let stranger = Address::generate(&f.env);
+
+        let r = f
+            .tipping
+            .try_set_tip_limits(&stranger, &f.token.address, &limits(1, 0, 0));
+        assert_eq!(r, Err(Ok(Error::Unauthorized)));
+
+        f.tipping.grant_admin(&f.owner, &admin);
+        f.tipping.set_tip_limits(&admin, &f.token.address, &limits(1, 0, 0));
+        assert_eq!(f.tipping.get_tip_limits(&f.token.address), limits(1, 0, 0));
+
+        let r = f.tipping.try_grant_admin(&f.owner, &admin);
+        assert_eq!(r, Err(Ok(Error::InvalidRoleChange)));
+    }
+
+    #[test]
+    fn pause_blocks_tips_until_unpaused() {
+        let f = setup();
+        let recipient = Address::generate(&f.env);
+
+        f.tipping.pause(&f.owner, &reason(&f.env));
+        assert!(f.tipping.is_paused());
+        assert_eq!(tip(&f, &recipient, 1), Err(Error::ContractPaused));
+        let r = f
+            .tipping
+            .try_create_tip_stream(&f.tipper, &recipient, &f.token.address, &1, &60, &1);
+        assert_eq!(r, Err(Ok(Error::ContractPaused)));
+        assert_eq!(
+            f.tipping.try_pause(&f.owner, &reason(&f.env)),
+            Err(Ok(Error::AlreadyPaused))
+        );
+
+        f.tipping.unpause(&f.owner, &reason(&f.env));
+        assert!(tip(&f, &recipient, 1).is_ok());
+        assert_eq!(
+            f.tipping.try_unpause(&f.owner, &reason(&f.env)),
+            Err(Ok(Error::NotPaused))
+        );
+    }
+
+    #[test]
+    fn only_admin_or_owner_pauses() {
+        let f = setup();
+        let stranger = Address::generate(&f.env);
+        let r = f.tipping.try_pause(&stranger, &reason(&f.env));
+        assert_eq!(r, Err(Ok(Error::Unauthorized)));
+        assert!(!f.tipping.is_paused());
+    }
+}
@@ -0,0 +1,200 @@
+/// Recurring tip-stream tests for the anonymous-tipping contract.
+#[cfg(test)]
+mod stream {
+    extern crate std;
+
+    use soroban_sdk::{
+        testutils::{Events as _, Ledger as _},
+        vec, Env, IntoVal, Symbol,
+    };
+
+    use crate::test_support::{self, Fixture};
+    use crate::{AnonymousTipping, Error, SettlementReceiptEvent, StreamState};
+
+    const DAY: u64 = 24 * 60 * 60;
+
+    fn setup<'a>() -> Fixture<'a> {
+        test_support::setup(1_000)
+    }
+
+    fn create(f: &Fixture, amount: i128, max_periods: u32) -> u64 {
+        f.tipping.create_tip_stream(
+            &f.tipper,
+            &f.recipient,
+            &f.token.address,
+            &amount,
+            &DAY,
+            &max_periods,
+        )
+    }
+
+    fn advance(env: &Env, secs: u64) {
+        env.ledger().with_mut(|li| li.timestamp += secs);
+    }
+
+    /// Advance time and the ledger sequence together, at 5s per ledger.
+    fn advance_ledgers(env: &Env, secs: u64) {
+        env.ledger().with_mut(|li| {
+            li.timestamp += secs;
+            li.sequence_number += (secs / 5) as u32;
+        });
+    }
+
+    #[test]
+    fn create_reserves_allowance_without_moving_funds() {
+        let f = setup();
+        let id = create(&f, 10, 5);
+
+        assert_eq!(id, 1);
+        assert_eq!(f.token.balance(&f.tipper), 1_000);
+        assert_eq!(f.token.allowance(&f.tipper, &f.tipping.address), 50);
+        let stream = f.tipping.get_stream(&id).unwrap();
+        assert_eq!(stream.state, StreamState::Active);
+        assert_eq!(stream.periods_paid, 0);
+        assert_eq!(f.tipping.settle_due(&id), vec![&f.env]);
+    }
+
+    #[test]
+    fn settle_due_pays_elapsed_periods_with_receipts() {
+        let f = setup();
+        let id = create(&f, 10, 5);
+        advance(&f.env, 2 * DAY + 1);
+
+        let ids = f.tipping.settle_due(&id);
+        let events = f.env.events().all();
+        let receipts: std::vec::Vec<SettlementReceiptEvent> = events
+            .iter()
+            .filter(|(_, topics, _)| {
+                let t: Symbol = topics.get(0).unwrap().into_val(&f.env);
+                t == Symbol::new(&f.env, "tip_settl")
+            })
+            .map(|(_, _, data)| data.into_val(&f.env))
+            .collect();
+
+        assert_eq!(ids, vec![&f.env, 1, 2]);
+        assert_eq!(receipts.len(), 2);
+        assert_eq!(receipts[0].settlement_id, 1);
+        assert_eq!(receipts[1].amount, 10);
+        assert_eq!(f.token.balance(&f.recipient), 20);
+        assert_eq!(f.token.allowance(&f.tipper, &f.tipping.address), 30);
+        assert_eq!(f.tipping.get_stream(&id).unwrap().periods_paid, 2);
+        assert_eq!(f.tipping.settle_due(&id), vec![&f.env]);
+    }
+
+    #[test]
+    fn stream_stops_at_max_periods() {
+        let f = setup();
+        let id = create(&f, 10, 3);
+        advance(&f.env, 30 * DAY);
+
+        assert_eq!(f.tipping.get_stream_due(&id), 3);
+        assert_eq!(f.tipping.settle_due(&id).len(), 3);
+        assert_eq!(f.token.balance(&f.recipient), 30);
+        assert_eq!(f.tipping.get_stream_due(&id), 0);
+    }
+
+    #[test]
+    fn settle_due_is_capped_per_call() {
+        let f = setup();
+        let cap = AnonymousTipping::MAX_SETTLE_PERIODS;
+        let id = create(&f, 1, cap + 10);
+        advance(&f.env, (cap as u64 + 10) * DAY);
+
+        assert_eq!(f.tipping.settle_due(&id).len(), cap);
+        assert_eq!(f.tipping.settle_due(&id).len(), 10);
+        assert_eq!(f.token.balance(&f.recipient), (cap + 10) as i128);
+    }
+
+    #[test]
+    fn cancel_keeps_elapsed_periods_payable() {
+        let f = setup();
+        let id = create(&f, 10, 5);
+        advance(&f.env, DAY);
+
+        f.tipping.cancel_stream(&f.tipper, &id);
+        assert_eq!(f.token.allowance(&f.tipper, &f.tipping.address), 10);
+
+        advance(&f.env, 10 * DAY);
+        assert_eq!(f.tipping.settle_due(&id).len(), 1);
+        assert_eq!(f.token.balance(&f.recipient), 10);
+        assert_eq!(f.tipping.get_stream(&id).unwrap().state, StreamState::Cancelled);
+
+        let r = f.tipping.try_cancel_stream(&f.tipper, &id);
+        assert_eq!(r, Err(Ok(Error::StreamNotActive)));
+    }
+
+    #[test]
+    fn only_tipper_cancels() {
+        let f = setup();
+        let id = create(&f, 10, 5);
+        let r = f.tipping.try_cancel_stream(&f.recipient, &id);
+        assert_eq!(r, Err(Ok(Error::Unauthorized)));
+    }
+
+    #[test]
+    fn revoked_allowance_fails_settlement() {
+        let f = setup();
+        let id = create(&f, 10, 5);
+        let expiration = f.env.ledger().sequence() + 100;
+        f.token.approve(&f.tipper, &f.tipping.address, &0, &expiration);
+        advance(&f.env, DAY);
+
+        let r = f.tipping.try_settle_due(&id);
+        assert_eq!(r, Err(Ok(Error::AllowanceExhausted)));
+        assert_eq!(f.tipping.get_stream(&id).unwrap().periods_paid, 0);
+    }
+
+    #[test]
+    fn allowance_expires_after_stream_end() {
+        let f = setup();
+        create(&f, 10, 2);
+
+        // Two daily periods plus the one-week settlement grace.
+        advance_ledgers(&f.env, 8 * DAY);
+        assert_eq!(f.token.allowance(&f.tipper, &f.tipping.address), 20);
+        advance_ledgers(&f.env, 2 * DAY);
+        assert_eq!(f.token.allowance(&f.tipper, &f.tipping.address), 0);
+    }
+
+    #[test]
+    fn concurrent_streams_share_allowance() {
+        let f = setup();
+        let long = create(&f, 5, 100);
+        let short = create(&f, 10, 2);
+        assert_eq!(f.token.allowance(&f.tipper, &f.tipping.address), 520);
+
+        // Past the short stream's end: the long stream's expiry still holds.
+        advance_ledgers(&f.env, 30 * DAY);
+        assert_eq!(f.tipping.settle_due(&short).len(), 2);
+        assert_eq!(f.tipping.settle_due(&long).len(), 30);
+        assert_eq!(f.token.balance(&f.recipient), 170);
+        assert_eq!(f.token.allowance(&f.tipper, &f.tipping.address), 350);
+
+        // Starting another stream adds to, rather than replaces, what is left.
+        create(&f, 1, 10);
+        assert_eq!(f.token.allowance(&f.tipper, &f.tipping.address), 360);
+
+        f.tipping.cancel_stream(&f.tipper, &long);
+        assert_eq!(f.token.allowance(&f.tipper, &f.tipping.address), 10);
+    }
+
+    #[test]
+    fn invalid_stream_parameters_rejected() {
+        let f = setup();
+        let asset = f.token.address.clone();
+        let t = &f.tipping;
+
+        let r = t.try_create_tip_stream(&f.tipper, &f.recipient, &asset, &0, &DAY, &1);
+        assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
+        let r = t.try_create_tip_stream(&f.tipper, &f.recipient, &asset, &1, &0, &1);
+        assert_eq!(r, Err(Ok(Error::InvalidStream)));
+        let r = t.try_create_tip_stream(&f.tipper, &f.recipient, &asset, &1, &DAY, &0);
+        assert_eq!(r, Err(Ok(Error::InvalidStream)));
+        let too_many = AnonymousTipping::MAX_STREAM_PERIODS + 1;
+        let r = t.try_create_tip_stream(&f.tipper, &f.recipient, &asset, &1, &DAY, &too_many);
+        assert_eq!(r, Err(Ok(Error::InvalidStream)));
+        let r = t.try_create_tip_stream(&f.tipper, &f.recipient, &asset, &i128::MAX, &DAY, &2);
+        assert_eq!(r, Err(Ok(Error::TotalOverflow)));
+        assert_eq!(t.try_settle_due(&99), Err(Ok(Error::StreamNotFound)));
+    }
+}
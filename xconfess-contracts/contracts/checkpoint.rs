@@ -1,7 +1,9 @@
-use soroban_sdk::{contracttype, Env, Vec};
-use crate::confession::Confession;
-use crate::reaction::Reaction;
-use crate::report::Report;
+use soroban_sdk::{contracttype, Env, Map, Symbol, Vec};
+
+use crate::events::{latest_stream_nonce, EVENT_STREAMS};
+
+/// Current `CheckpointSummary` layout.
+pub const CHECKPOINT_VERSION: u32 = 2;
 
 /// Snapshot summaries for indexer consumption
 #[contracttype]
@@ -13,61 +15,81 @@ pub struct CheckpointSummary {
     pub latest_confession_id: u64,
     pub latest_reaction_id: u64,
     pub latest_report_id: u64,
+    /// Contract-wide nonce per event stream in `events::EVENT_STREAMS`.
+    pub event_nonces: Map<Symbol, u64>,
+    /// Ledger the summary was read at.
+    pub ledger_sequence: u32,
     pub version_marker: u32, // allows backward-compatible upgrades
 }
 
-/// Snapshot page for paginated reading
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
+/// Live counters a contract supplies to `get_checkpoint_summary`.
+pub struct CheckpointCounters {
+    pub total_confessions: u64,
+    pub total_reactions: u64,
+    pub total_reports: u64,
+    pub latest_confession_id: u64,
+    pub latest_reaction_id: u64,
+    pub latest_report_id: u64,
+}
+
+/// Snapshot page for paginated reading.
+///
+/// Contract types cannot be generic, so entrypoints return a concrete
+/// `#[contracttype]` with the same fields, built via `From<SnapshotPage<T>>`.
 pub struct SnapshotPage<T> {
     pub items: Vec<T>,
     pub next_cursor: Option<u64>, // next starting id for pagination
 }
 
+/// Walk direction for paginated snapshot reads.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SnapshotOrder {
+    Ascending,
+    Descending,
+}
+
 /// ===========================================
 /// API: deterministic checkpoint reads
 /// ===========================================
-pub fn get_checkpoint_summary(
-    env: &Env,
-    confessions: &Vec<Confession>,
-    reactions: &Vec<Reaction>,
-    reports: &Vec<Report>,
-) -> CheckpointSummary {
-    let total_confessions = confessions.len() as u64;
-    let total_reactions = reactions.len() as u64;
-    let total_reports = reports.len() as u64;
-
-    let latest_confession_id = confessions.last().map(|c| c.id).unwrap_or(0);
-    let latest_reaction_id = reactions.last().map(|r| r.id).unwrap_or(0);
-    let latest_report_id = reports.last().map(|rep| rep.id).unwrap_or(0);
+pub fn get_checkpoint_summary(env: &Env, counters: CheckpointCounters) -> CheckpointSummary {
+    let mut event_nonces = Map::new(env);
+    for stream in EVENT_STREAMS {
+        event_nonces.set(stream.clone(), latest_stream_nonce(env, stream));
+    }
 
     CheckpointSummary {
-        total_confessions,
-        total_reactions,
-        total_reports,
-        latest_confession_id,
-        latest_reaction_id,
-        latest_report_id,
-        version_marker: 1,
+        total_confessions: counters.total_confessions,
+        total_reactions: counters.total_reactions,
+        total_reports: counters.total_reports,
+        latest_confession_id: counters.latest_confession_id,
+        latest_reaction_id: counters.latest_reaction_id,
+        latest_report_id: counters.latest_report_id,
+        event_nonces,
+        ledger_sequence: env.ledger().sequence(),
+        version_marker: CHECKPOINT_VERSION,
     }
 }
 
 /// ===========================================
 /// API: deterministic paginated snapshot reads
 /// ===========================================
-pub fn get_snapshot_page<T: Clone>(
+pub fn get_snapshot_page<T>(
     items: &Vec<T>,
     start_id: u64,
     page_size: u64,
     get_id: impl Fn(&T) -> u64,
-) -> SnapshotPage<T> {
+) -> SnapshotPage<T>
+where
+    T: Clone + soroban_sdk::TryFromVal<Env, soroban_sdk::Val> + soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+{
     let mut page_items = Vec::new(items.env());
     let mut next_cursor: Option<u64> = None;
 
     for item in items.iter() {
         let id = get_id(&item);
-        if id > start_id && page_items.len() < page_size as usize {
-            page_items.push(item.clone());
+        if id > start_id && (page_items.len() as u64) < page_size {
+            page_items.push_back(item.clone());
         } else if id > start_id {
             next_cursor = Some(id);
             break;
@@ -78,4 +100,4 @@ pub fn get_snapshot_page<T: Clone>(
         items: page_items,
         next_cursor,
     }
-}
\ No newline at end of file
+}
@@ -17,7 +17,7 @@
 //     B3  unauthorized caller is rejected
 //     B4  cannot update a deleted confession
 //     B5  cannot update a nonexistent confession
-//     B6  all valid status transitions available to author
+//     B6  author cannot set or clear Flagged / Hidden
 //     B7  all valid status transitions available to admin
 //
 //   Block C – create_confession (paused)
@@ -158,10 +158,10 @@ fn b1_author_can_update_own_confession_status() {
     let (env, client, _admin, author) = setup();
     let id = create(&client, &env, &author, 10);
 
-    client.update_status(&author, &id, &ConfessionStatus::Flagged, &5_000_000);
+    client.update_status(&author, &id, &ConfessionStatus::Deleted, &5_000_000);
 
     let conf = client.get_confession(&id);
-    assert_eq!(conf.status, ConfessionStatus::Flagged);
+    assert_eq!(conf.status, ConfessionStatus::Deleted);
     assert_eq!(conf.updated_at, 5_000_000);
 }
 
@@ -213,17 +213,22 @@ fn b5_update_nonexistent_confession_panics() {
     client.update_status(&author, &9_999, &ConfessionStatus::Flagged, &1_000_000);
 }
 
-/// B6: author can exercise all non-terminal transitions on their own confession.
+/// B6: Flagged and Hidden are moderation states; the author can neither set
+///     them nor move their confession out of them.
 #[test]
-fn b6_author_can_set_all_non_terminal_statuses() {
-    let (env, client, _admin, author) = setup();
+fn b6_author_cannot_set_or_clear_moderation_statuses() {
+    let (env, client, admin, author) = setup();
     let id = create(&client, &env, &author, 14);
 
-    client.update_status(&author, &id, &ConfessionStatus::Flagged, &2_000_000);
-    assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
+    let result = client.try_update_status(&author, &id, &ConfessionStatus::Flagged, &2_000_000);
+    assert!(result.is_err());
+    let result = client.try_update_status(&author, &id, &ConfessionStatus::Hidden, &2_000_000);
+    assert!(result.is_err());
 
-    client.update_status(&author, &id, &ConfessionStatus::Active, &3_000_000);
-    assert_eq!(client.get_confession(&id).status, ConfessionStatus::Active);
+    client.update_status(&admin, &id, &ConfessionStatus::Flagged, &2_000_000);
+    let result = client.try_update_status(&author, &id, &ConfessionStatus::Active, &3_000_000);
+    assert!(result.is_err());
+    assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
 }
 
 /// B7: admin can transition a confession through all non-terminal statuses.
@@ -296,7 +301,7 @@ fn d2_update_status_succeeds_after_unpause() {
     pause_contract(&client, &admin);
     unpause_contract(&client, &admin);
 
-    client.update_status(&author, &id, &ConfessionStatus::Flagged, &3_000_000);
+    client.update_status(&admin, &id, &ConfessionStatus::Flagged, &3_000_000);
     assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
 }
 
@@ -407,13 +412,13 @@ fn f3_admin_update_then_delete_sequence() {
 /// F4: updated_at is stamped correctly by both update_status and delete_confession.
 #[test]
 fn f4_updated_at_is_set_correctly() {
-    let (env, client, _admin, author) = setup();
+    let (env, client, admin, author) = setup();
     let id = create(&client, &env, &author, 93);
 
     // updated_at starts at 0
     assert_eq!(client.get_confession(&id).updated_at, 0);
 
-    client.update_status(&author, &id, &ConfessionStatus::Flagged, &5_555_000);
+    client.update_status(&admin, &id, &ConfessionStatus::Flagged, &5_555_000);
     assert_eq!(client.get_confession(&id).updated_at, 5_555_000);
 
     client.delete_confession(&author, &id, &6_666_000);
@@ -424,12 +429,12 @@ fn f4_updated_at_is_set_correctly() {
 ///     The index tracks creation, not lifecycle state.
 #[test]
 fn f5_author_index_unchanged_by_status_changes() {
-    let (env, client, _admin, author) = setup();
+    let (env, client, admin, author) = setup();
 
     let id1 = create(&client, &env, &author, 94);
     let id2 = create(&client, &env, &author, 95);
 
-    client.update_status(&author, &id1, &ConfessionStatus::Flagged, &2_000_000);
+    client.update_status(&admin, &id1, &ConfessionStatus::Flagged, &2_000_000);
     client.delete_confession(&author, &id2, &3_000_000);
 
     let ids = client.get_author_confessions(&author);
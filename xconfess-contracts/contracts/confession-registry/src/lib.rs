@@ -4,21 +4,49 @@
 mod confession_reg_auth;
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec,
+    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map, String, Symbol,
+    Vec,
 };
 
 #[path = "../../access_control.rs"]
 mod access_control;
+#[path = "../../checkpoint.rs"]
+mod checkpoint;
+#[path = "../../configurable/mod.rs"]
+mod configurable;
 #[path = "../../error.rs"]
 mod error;
 #[path = "../../events.rs"]
 mod events;
 #[path = "../../governance/mod.rs"]
 mod governance;
+#[path = "../../pagination/mod.rs"]
+mod pagination;
 #[path = "../../emergency_pause/mod.rs"]
 mod emergency_pause;
+#[path = "../../report.rs"]
+mod report;
 // mod confession_reg_auth;
 
+use error::ContractError;
+use pagination::confession::ConfessionKey;
+pub use checkpoint::{CheckpointSummary, SnapshotOrder};
+use checkpoint::{CheckpointCounters, SnapshotPage};
+pub use configurable::Config;
+pub use events::{CaseOutcome, CaseState};
+use report::{ReportContract, ReportError};
+
+impl From<ReportError> for ContractError {
+    fn from(value: ReportError) -> Self {
+        match value {
+            ReportError::ReasonEmpty => ContractError::InvalidReportReason,
+            ReportError::ReasonTooLong => ContractError::ReportReasonTooLong,
+            ReportError::CooldownActive => ContractError::CooldownActive,
+            ReportError::ReportExists => ContractError::ReportExists,
+        }
+    }
+}
+
 // ─── Data Types ───
 
 /// Status of a confession in the registry.
@@ -28,6 +56,8 @@ pub enum ConfessionStatus {
     Active,
     Deleted,
     Flagged,
+    /// Hidden by a moderation outcome; only an admin can restore it.
+    Hidden,
 }
 
 /// On-chain confession record.
@@ -48,6 +78,55 @@ pub struct Confession {
     pub status: ConfessionStatus,
 }
 
+/// Per-type reaction counts for a single confession.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReactionSummary {
+    pub confession_id: u64,
+    /// Reaction type → number of reactors currently holding it.
+    pub counts: Map<Symbol, u32>,
+    /// Sum of all per-type counts.
+    pub total: u32,
+    /// Latest reaction event nonce, for reconciling against indexed events.
+    pub nonce: u64,
+}
+
+/// Moderation case grouping all open reports against one confession.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModerationCase {
+    pub confession_id: u64,
+    /// Carries the outcome once the case is resolved.
+    pub state: CaseState,
+    /// Reports received since the case was opened.
+    pub report_count: u32,
+    /// Admin that took the case into review or resolved it.
+    pub reviewer: Option<Address>,
+    /// Ledger timestamp when the case was opened.
+    pub opened_at: u64,
+    /// Ledger timestamp of the last state transition.
+    pub updated_at: u64,
+}
+
+/// One page of confessions: the contract-returnable form of
+/// `checkpoint::SnapshotPage<Confession>`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfessionPage {
+    pub items: Vec<Confession>,
+    /// Cursor for the next page, or `None` when the index is exhausted.
+    pub next_cursor: Option<u64>,
+}
+
+impl From<SnapshotPage<Confession>> for ConfessionPage {
+    fn from(page: SnapshotPage<Confession>) -> Self {
+        ConfessionPage {
+            items: page.items,
+            next_cursor: page.next_cursor,
+        }
+    }
+}
+
 /// Storage keys used by the contract.
 #[contracttype]
 pub enum DataKey {
@@ -57,10 +136,232 @@ pub enum DataKey {
     Confession(u64),
     /// Maps content_hash → confession_id for uniqueness checks.
     HashIndex(BytesN<32>),
-    /// Tracks confession IDs owned by an author.
-    AuthorConfessions(Address),
+    /// Number of confessions created by an author.
+    AuthorCount(Address),
+    /// Fixed-size chunk of an author's confession IDs, in creation order.
+    AuthorChunk(Address, u32),
+    /// Number of entries in the global confession index.
+    IndexCount,
+    /// Fixed-size chunk of `ConfessionKey::Index` keys, in creation order.
+    IndexChunk(u32),
     /// Contract admin address.
     Admin,
+    /// Allow-listed reaction types.
+    ReactionTypes,
+    /// Reaction type held by a reactor on a confession.
+    Reaction(u64, Address),
+    /// Per-type reaction counts for a confession.
+    ReactionCounts(u64),
+    /// Current moderation case for a confession.
+    Case(u64),
+    /// Reactions currently held across all confessions.
+    ReactionTotal,
+    /// Reactions ever recorded; the latest reaction ID.
+    ReactionSeq,
+    /// Reports ever recorded; the latest report ID.
+    ReportSeq,
+}
+
+/// Reaction types accepted until an admin replaces the allow-list.
+fn default_reaction_types(env: &Env) -> Vec<Symbol> {
+    Vec::from_array(
+        env,
+        [
+            symbol_short!("like"),
+            symbol_short!("love"),
+            symbol_short!("laugh"),
+            symbol_short!("sad"),
+            symbol_short!("support"),
+        ],
+    )
+}
+
+fn reaction_types(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReactionTypes)
+        .unwrap_or_else(|| default_reaction_types(env))
+}
+
+/// Confession IDs stored per author index chunk.
+pub const AUTHOR_CHUNK_SIZE: u32 = 50;
+/// Index keys stored per global creation-order index chunk.
+pub const INDEX_CHUNK_SIZE: u32 = 50;
+/// Upper bound on page `limit` for `list_confessions` and
+/// `get_author_confessions_page`.
+pub const MAX_PAGE_SIZE: u32 = 50;
+/// Upper bound on index positions examined by one page request.
+pub const MAX_AUTHOR_PAGE_SCAN: u32 = 200;
+/// Upper bound on global index positions examined by one `list_confessions`
+/// call.
+pub const MAX_FEED_PAGE_SCAN: u32 = 200;
+
+fn author_count(env: &Env, author: &Address) -> u64 {
+    let key = DataKey::AuthorCount(author.clone());
+    let count = env.storage().persistent().get(&key).unwrap_or(0u64);
+    if count > 0 {
+        extend_persistent(env, &key);
+    }
+    count
+}
+
+fn read_author_chunk(env: &Env, author: &Address, chunk: u32) -> Vec<u64> {
+    let key = DataKey::AuthorChunk(author.clone(), chunk);
+    match env.storage().persistent().get(&key) {
+        Some(ids) => {
+            extend_persistent(env, &key);
+            ids
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Append `id` to the author's index, starting a new chunk when the last one
+/// is full.
+fn append_author_confession(env: &Env, author: &Address, id: u64) {
+    let count = author_count(env, author);
+    let chunk = (count / AUTHOR_CHUNK_SIZE as u64) as u32;
+
+    let mut ids = read_author_chunk(env, author, chunk);
+    ids.push_back(id);
+
+    let chunk_key = DataKey::AuthorChunk(author.clone(), chunk);
+    env.storage().persistent().set(&chunk_key, &ids);
+    extend_persistent(env, &chunk_key);
+
+    let count_key = DataKey::AuthorCount(author.clone());
+    env.storage().persistent().set(&count_key, &(count + 1));
+    extend_persistent(env, &count_key);
+}
+
+fn index_count(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IndexCount)
+        .unwrap_or(0u64)
+}
+
+fn read_index_chunk(env: &Env, chunk: u32) -> Vec<(u64, u64)> {
+    let key = DataKey::IndexChunk(chunk);
+    match env.storage().persistent().get(&key) {
+        Some(keys) => {
+            extend_persistent(env, &key);
+            keys
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Record `id` under `ConfessionKey::Index((created_seq, id))` and append the
+/// key to the walkable creation-order chunks.
+fn append_index(env: &Env, id: u64) {
+    let index_key = (env.ledger().sequence() as u64, id);
+    let entry_key = ConfessionKey::Index(index_key);
+    env.storage().persistent().set(&entry_key, &id);
+    env.storage().persistent().extend_ttl(
+        &entry_key,
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
+
+    let count = index_count(env);
+    let chunk = (count / INDEX_CHUNK_SIZE as u64) as u32;
+    let mut keys = read_index_chunk(env, chunk);
+    keys.push_back(index_key);
+
+    let chunk_key = DataKey::IndexChunk(chunk);
+    env.storage().persistent().set(&chunk_key, &keys);
+    extend_persistent(env, &chunk_key);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::IndexCount, &(count + 1));
+    extend_persistent(env, &DataKey::IndexCount);
+}
+
+/// Resolve a 0-based index position to its confession. `None` marks a gap:
+/// the index entry or the record it points at is no longer present.
+fn read_indexed_confession(
+    env: &Env,
+    chunk: &Vec<(u64, u64)>,
+    position: u64,
+) -> Option<Confession> {
+    let index_key = chunk.get((position % INDEX_CHUNK_SIZE as u64) as u32)?;
+    let entry_key = ConfessionKey::Index(index_key);
+    let id: u64 = env.storage().persistent().get(&entry_key)?;
+    env.storage().persistent().extend_ttl(
+        &entry_key,
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
+    let key = DataKey::Confession(id);
+    let confession = env.storage().persistent().get(&key)?;
+    extend_persistent(env, &key);
+    Some(confession)
+}
+
+/// Persistent entries are extended to ~30 days once they fall below ~7 days
+/// (at ~5s per ledger).
+const PERSISTENT_TTL_THRESHOLD: u32 = 17_280 * 7;
+const PERSISTENT_TTL_EXTEND_TO: u32 = 17_280 * 30;
+
+fn extend_persistent(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+}
+
+fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+}
+
+/// Read a confession and extend its TTL.
+fn read_confession(env: &Env, id: u64) -> Confession {
+    let key = DataKey::Confession(id);
+    let confession = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .expect("confession not found");
+    extend_persistent(env, &key);
+    confession
+}
+
+/// Write a confession and extend its TTL.
+fn write_confession(env: &Env, confession: &Confession) {
+    let key = DataKey::Confession(confession.id);
+    env.storage().persistent().set(&key, confession);
+    extend_persistent(env, &key);
+}
+
+fn read_counter(env: &Env, key: &DataKey) -> u64 {
+    env.storage().instance().get(key).unwrap_or(0u64)
+}
+
+fn increment_counter(env: &Env, key: &DataKey) {
+    env.storage().instance().set(key, &(read_counter(env, key) + 1));
+}
+
+fn decrement_counter(env: &Env, key: &DataKey) {
+    env.storage()
+        .instance()
+        .set(key, &read_counter(env, key).saturating_sub(1));
+}
+
+fn read_case(env: &Env, confession_id: u64) -> ModerationCase {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Case(confession_id))
+        .expect("moderation case not found")
+}
+
+fn reaction_counts(env: &Env, confession_id: u64) -> Map<Symbol, u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReactionCounts(confession_id))
+        .unwrap_or_else(|| Map::new(env))
 }
 
 // ─── Contract ───
@@ -82,6 +383,34 @@ impl ConfessionRegistry {
 
         // Also initialize common access control
         access_control::init_owner(&env, &admin).expect("owner initialization failed");
+
+        Config::init(
+            &env,
+            configurable::config::DEFAULT_LIMITS,
+            configurable::config::DEFAULT_THRESHOLD,
+            configurable::config::DEFAULT_WINDOW,
+        );
+    }
+
+    // ─── Configuration ───
+
+    /// Get the current contract configuration.
+    pub fn get_config(env: Env) -> Config {
+        Config::load(&env)
+    }
+
+    /// Update the contract configuration. Owner only; at most once per hour.
+    ///
+    /// Emits: `("ConfigUpdate",)` → `(old_limits, old_threshold, old_window,
+    /// new_limits, new_threshold, new_window)`
+    pub fn update_config(
+        env: Env,
+        caller: Address,
+        limits: u32,
+        threshold: u32,
+        window: u32,
+    ) -> Config {
+        Config::update(&env, &caller, limits, threshold, window)
     }
 
     // ─── Governance ───
@@ -141,7 +470,7 @@ impl ConfessionRegistry {
         // Enforce uniqueness on content_hash
         if env
             .storage()
-            .instance()
+            .persistent()
             .has(&DataKey::HashIndex(content_hash.clone()))
         {
             panic!("confession with this content hash already exists");
@@ -166,25 +495,19 @@ impl ConfessionRegistry {
         };
 
         // Persist
-        env.storage()
-            .instance()
-            .set(&DataKey::Confession(id), &confession);
-        env.storage()
-            .instance()
-            .set(&DataKey::HashIndex(content_hash.clone()), &id);
+        write_confession(&env, &confession);
+        let hash_key = DataKey::HashIndex(content_hash.clone());
+        env.storage().persistent().set(&hash_key, &id);
+        extend_persistent(&env, &hash_key);
 
-        // Track author → confession index
-        let mut author_ids: Vec<u64> = env
-            .storage()
-            .instance()
-            .get(&DataKey::AuthorConfessions(author.clone()))
-            .unwrap_or_else(|| Vec::new(&env));
-        author_ids.push_back(id);
-        env.storage()
-            .instance()
-            .set(&DataKey::AuthorConfessions(author.clone()), &author_ids);
+        // Track author → confession index and the global creation-order index
+        append_author_confession(&env, &author, id);
+        append_index(&env, id);
+
+        extend_instance(&env);
 
         // Emit event
+        events::next_confession_nonce(&env, id);
         let event_topic = Symbol::new(&env, "confession_created");
         env.events()
             .publish((event_topic, id), (author, content_hash, timestamp));
@@ -196,26 +519,176 @@ impl ConfessionRegistry {
 
     /// Get a confession by ID.
     pub fn get_confession(env: Env, id: u64) -> Confession {
-        env.storage()
-            .instance()
-            .get(&DataKey::Confession(id))
-            .expect("confession not found")
+        read_confession(&env, id)
     }
 
     /// Get a confession ID by its content hash.
     pub fn get_by_hash(env: Env, content_hash: BytesN<32>) -> u64 {
-        env.storage()
-            .instance()
-            .get(&DataKey::HashIndex(content_hash))
-            .expect("no confession with that hash")
+        let key = DataKey::HashIndex(content_hash);
+        let id = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .expect("no confession with that hash");
+        extend_persistent(&env, &key);
+        id
     }
 
     /// Get all confession IDs for an author.
+    ///
+    /// Reads every index chunk; prefer `get_author_confessions_page` for
+    /// prolific authors.
     pub fn get_author_confessions(env: Env, author: Address) -> Vec<u64> {
-        env.storage()
-            .instance()
-            .get(&DataKey::AuthorConfessions(author))
-            .unwrap_or_else(|| Vec::new(&env))
+        let count = author_count(&env, &author);
+        let mut ids = Vec::new(&env);
+        let chunks = count.div_ceil(AUTHOR_CHUNK_SIZE as u64) as u32;
+        for chunk in 0..chunks {
+            ids.append(&read_author_chunk(&env, &author, chunk));
+        }
+        ids
+    }
+
+    /// Get a page of an author's confessions in creation order.
+    ///
+    /// - `cursor`: position to resume from (`None` starts at the beginning).
+    /// - `limit`: maximum records returned, `1..=MAX_PAGE_SIZE`.
+    /// - `status`: only return confessions in this status, if set.
+    ///
+    /// At most `MAX_AUTHOR_PAGE_SCAN` positions are examined per call, so a
+    /// filtered page may hold fewer than `limit` records while `next_cursor`
+    /// is still set. `next_cursor` is `None` once the index is exhausted.
+    pub fn get_author_confessions_page(
+        env: Env,
+        author: Address,
+        cursor: Option<u64>,
+        limit: u32,
+        status: Option<ConfessionStatus>,
+    ) -> ConfessionPage {
+        if limit == 0 || limit > MAX_PAGE_SIZE {
+            panic!("{}", ContractError::InvalidInput.message());
+        }
+
+        let count = author_count(&env, &author);
+        let mut items = Vec::new(&env);
+        let mut position = cursor.unwrap_or(0);
+        let scan_end = count.min(position.saturating_add(MAX_AUTHOR_PAGE_SCAN as u64));
+
+        let mut chunk_index = u32::MAX;
+        let mut chunk = Vec::new(&env);
+        while position < scan_end && items.len() < limit {
+            let wanted = (position / AUTHOR_CHUNK_SIZE as u64) as u32;
+            if wanted != chunk_index {
+                chunk_index = wanted;
+                chunk = read_author_chunk(&env, &author, chunk_index);
+            }
+
+            let id = chunk
+                .get((position % AUTHOR_CHUNK_SIZE as u64) as u32)
+                .expect("author index out of sync");
+            let confession = read_confession(&env, id);
+            if status.as_ref().is_none_or(|s| *s == confession.status) {
+                items.push_back(confession);
+            }
+            position += 1;
+        }
+
+        ConfessionPage {
+            items,
+            next_cursor: if position < count { Some(position) } else { None },
+        }
+    }
+
+    /// Walk the global confession index, oldest or newest first.
+    ///
+    /// - `cursor`: 1-based index position to resume from, inclusive (`None`
+    ///   starts at the first or latest entry depending on `order`). Positions
+    ///   match confession IDs until the index has gaps.
+    /// - `limit`: maximum records returned, `1..=MAX_PAGE_SIZE`.
+    ///
+    /// Deleted confessions are returned with their `Deleted` status. Gaps,
+    /// where an index entry or its record is no longer present, are skipped.
+    /// At most `MAX_FEED_PAGE_SCAN` positions are examined per call, so a page
+    /// may hold fewer than `limit` records while `next_cursor` is still set.
+    /// `next_cursor` is `None` once the end of the index is reached.
+    pub fn list_confessions(
+        env: Env,
+        cursor: Option<u64>,
+        limit: u32,
+        order: SnapshotOrder,
+    ) -> ConfessionPage {
+        if limit == 0 || limit > MAX_PAGE_SIZE {
+            panic!("{}", ContractError::InvalidInput.message());
+        }
+
+        let count = index_count(&env);
+        let mut items = Vec::new(&env);
+        let mut position = match order {
+            SnapshotOrder::Ascending => cursor.unwrap_or(1).max(1),
+            SnapshotOrder::Descending => cursor.unwrap_or(count).min(count),
+        };
+
+        let mut scanned = 0;
+        let mut chunk_index = u32::MAX;
+        let mut chunk = Vec::new(&env);
+        while position >= 1
+            && position <= count
+            && items.len() < limit
+            && scanned < MAX_FEED_PAGE_SCAN
+        {
+            let offset = position - 1;
+            let wanted = (offset / INDEX_CHUNK_SIZE as u64) as u32;
+            if wanted != chunk_index {
+                chunk_index = wanted;
+                chunk = read_index_chunk(&env, chunk_index);
+            }
+
+            if let Some(confession) = read_indexed_confession(&env, &chunk, offset) {
+                items.push_back(confession);
+            }
+            scanned += 1;
+            position = match order {
+                SnapshotOrder::Ascending => position + 1,
+                SnapshotOrder::Descending => position - 1,
+            };
+        }
+
+        let next_cursor = if position >= 1 && position <= count {
+            Some(position)
+        } else {
+            None
+        };
+        SnapshotPage { items, next_cursor }.into()
+    }
+
+    /// Live counters, latest IDs and per-stream event nonces in one read, for
+    /// reconciling an off-chain index against chain state.
+    ///
+    /// Reports are never removed, so `total_reports == latest_report_id`.
+    /// The `confess` nonce counts every create, status change and delete,
+    /// including auto-flags and moderation outcomes.
+    pub fn get_checkpoint(env: Env) -> CheckpointSummary {
+        let latest_confession_id = Self::get_total_count(env.clone());
+        let latest_report_id = read_counter(&env, &DataKey::ReportSeq);
+
+        checkpoint::get_checkpoint_summary(
+            &env,
+            CheckpointCounters {
+                total_confessions: latest_confession_id,
+                total_reactions: read_counter(&env, &DataKey::ReactionTotal),
+                total_reports: latest_report_id,
+                latest_confession_id,
+                latest_reaction_id: read_counter(&env, &DataKey::ReactionSeq),
+                latest_report_id,
+            },
+        )
+    }
+
+    /// Extend the TTL of a confession and its hash index so they stay live
+    /// without needing a read. Anyone may call this.
+    pub fn bump_confession_ttl(env: Env, id: u64) {
+        let confession = read_confession(&env, id);
+        extend_persistent(&env, &DataKey::HashIndex(confession.content_hash));
+        extend_instance(&env);
     }
 
     /// Get the total number of confessions created.
@@ -228,11 +701,327 @@ impl ConfessionRegistry {
         next_id - 1
     }
 
+    // ─── Reactions ───
+
+    /// Replace the reaction-type allow-list. Admin or owner only.
+    ///
+    /// Existing reactions keep their type; only new reactions are checked.
+    pub fn set_reaction_types(env: Env, caller: Address, types: Vec<Symbol>) {
+        access_control::require_admin_or_owner(&env, &caller)
+            .unwrap_or_else(|_| panic!("{}", ContractError::Unauthorized.message()));
+
+        if types.is_empty() {
+            panic!("{}", ContractError::InvalidInput.message());
+        }
+
+        env.storage().instance().set(&DataKey::ReactionTypes, &types);
+    }
+
+    /// Get the current reaction-type allow-list.
+    pub fn get_reaction_types(env: Env) -> Vec<Symbol> {
+        reaction_types(&env)
+    }
+
+    /// React to a confession. Each reactor holds at most one reaction per
+    /// confession; call `unreact` first to change it.
+    ///
+    /// Emits: `("react",)` → `ReactionEvent`
+    pub fn react(env: Env, confession_id: u64, reactor: Address, reaction_type: Symbol) {
+        reactor.require_auth();
+
+        emergency_pause::assert_not_paused(&env)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+
+        let confession = read_confession(&env, confession_id);
+        if confession.status == ConfessionStatus::Deleted {
+            panic!("confession is deleted and cannot be reacted to");
+        }
+
+        if !reaction_types(&env).contains(&reaction_type) {
+            panic!("{}", ContractError::InvalidReactionType.message());
+        }
+
+        let reaction_key = DataKey::Reaction(confession_id, reactor.clone());
+        if env.storage().persistent().has(&reaction_key) {
+            panic!("{}", ContractError::ReactionExists.message());
+        }
+        env.storage().persistent().set(&reaction_key, &reaction_type);
+        extend_persistent(&env, &reaction_key);
+
+        let mut counts = reaction_counts(&env, confession_id);
+        let count = counts.get(reaction_type.clone()).unwrap_or(0);
+        counts.set(reaction_type.clone(), count + 1);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReactionCounts(confession_id), &counts);
+        extend_persistent(&env, &DataKey::ReactionCounts(confession_id));
+
+        increment_counter(&env, &DataKey::ReactionTotal);
+        increment_counter(&env, &DataKey::ReactionSeq);
+
+        events::emit_reaction(&env, confession_id, reactor, reaction_type, None);
+    }
+
+    /// Remove the reactor's reaction from a confession.
+    ///
+    /// Emits: `("unreact",)` → `ReactionEvent`
+    pub fn unreact(env: Env, confession_id: u64, reactor: Address) {
+        reactor.require_auth();
+
+        emergency_pause::assert_not_paused(&env)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+
+        let reaction_key = DataKey::Reaction(confession_id, reactor.clone());
+        let reaction_type: Symbol = env
+            .storage()
+            .persistent()
+            .get(&reaction_key)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotFound.message()));
+        env.storage().persistent().remove(&reaction_key);
+
+        let mut counts = reaction_counts(&env, confession_id);
+        let count = counts.get(reaction_type.clone()).unwrap_or(0);
+        if count <= 1 {
+            counts.remove(reaction_type.clone());
+        } else {
+            counts.set(reaction_type.clone(), count - 1);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReactionCounts(confession_id), &counts);
+        extend_persistent(&env, &DataKey::ReactionCounts(confession_id));
+
+        decrement_counter(&env, &DataKey::ReactionTotal);
+
+        events::emit_reaction_removed(&env, confession_id, reactor, reaction_type, None);
+    }
+
+    /// Get the reaction type `reactor` holds on a confession, if any.
+    pub fn get_reaction(env: Env, confession_id: u64, reactor: Address) -> Option<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Reaction(confession_id, reactor))
+    }
+
+    /// Get per-type reaction counts for a confession.
+    pub fn get_reaction_summary(env: Env, confession_id: u64) -> ReactionSummary {
+        let counts = reaction_counts(&env, confession_id);
+        let mut total: u32 = 0;
+        for count in counts.values().iter() {
+            total += count;
+        }
+
+        ReactionSummary {
+            confession_id,
+            counts,
+            total,
+            nonce: events::latest_reaction_nonce(&env, confession_id),
+        }
+    }
+
+    // ─── Moderation ───
+
+    /// Report a confession. The first report opens a moderation case; later
+    /// reports join it until it is resolved, after which a new case is opened.
+    /// An `Active` confession is flagged once its open case reaches the
+    /// configured `threshold` of distinct reporters.
+    ///
+    /// Emits: `("mod_case",)` → `ModerationCaseEvent` with state `Open`, and
+    /// `("confession_updated", id, "auto_flag")` → `(old_status, new_status, timestamp)`
+    /// when the threshold is crossed.
+    pub fn report_confession(env: Env, reporter: Address, confession_id: u64, reason: String) {
+        reporter.require_auth();
+
+        emergency_pause::assert_not_paused(&env)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+
+        let confession = read_confession(&env, confession_id);
+        if confession.status == ConfessionStatus::Deleted {
+            panic!("confession is deleted and cannot be reported");
+        }
+
+        ReportContract::submit_report_with_reason(&env, &reporter, confession_id, reason)
+            .unwrap_or_else(|err| panic!("{}", ContractError::from(err).message()));
+        increment_counter(&env, &DataKey::ReportSeq);
+
+        let now = env.ledger().timestamp();
+        let case = match env
+            .storage()
+            .persistent()
+            .get::<_, ModerationCase>(&DataKey::Case(confession_id))
+        {
+            Some(mut case) if !matches!(case.state, CaseState::Resolved(_)) => {
+                case.report_count += 1;
+                case.updated_at = now;
+                case
+            }
+            _ => ModerationCase {
+                confession_id,
+                state: CaseState::Open,
+                report_count: 1,
+                reviewer: None,
+                opened_at: now,
+                updated_at: now,
+            },
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Case(confession_id), &case);
+
+        extend_persistent(&env, &DataKey::Case(confession_id));
+
+        // Auto-flag once the open case gathers `threshold` distinct reporters.
+        // Each reporter can only report a confession once, so the case count
+        // is a distinct-reporter count.
+        let threshold = configurable::Config::load(&env).threshold;
+        if threshold > 0
+            && case.report_count >= threshold
+            && confession.status == ConfessionStatus::Active
+        {
+            let mut flagged = confession;
+            flagged.status = ConfessionStatus::Flagged;
+            flagged.updated_at = now;
+            write_confession(&env, &flagged);
+
+            events::next_confession_nonce(&env, confession_id);
+            let event_topic = Symbol::new(&env, "confession_updated");
+            env.events().publish(
+                (event_topic, confession_id, symbol_short!("auto_flag")),
+                (ConfessionStatus::Active, ConfessionStatus::Flagged, now),
+            );
+        }
+
+        events::emit_moderation_case(
+            &env,
+            confession_id,
+            reporter,
+            case.state,
+            case.report_count,
+        );
+    }
+
+    /// Take an open case into review. Admin or owner only.
+    ///
+    /// Emits: `("mod_case",)` → `ModerationCaseEvent` with state `UnderReview`
+    pub fn review_case(env: Env, caller: Address, confession_id: u64) {
+        access_control::require_admin_or_owner(&env, &caller)
+            .unwrap_or_else(|_| panic!("{}", ContractError::Unauthorized.message()));
+
+        emergency_pause::assert_not_paused(&env)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+
+        let mut case = read_case(&env, confession_id);
+        if case.state != CaseState::Open {
+            panic!("moderation case is not open");
+        }
+
+        case.state = CaseState::UnderReview;
+        case.reviewer = Some(caller.clone());
+        case.updated_at = env.ledger().timestamp();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Case(confession_id), &case);
+
+        extend_persistent(&env, &DataKey::Case(confession_id));
+
+        events::emit_moderation_case(
+            &env,
+            confession_id,
+            caller,
+            CaseState::UnderReview,
+            case.report_count,
+        );
+    }
+
+    /// Resolve a case and apply its outcome to the confession in the same
+    /// invocation. Admin or owner only.
+    ///
+    /// - `Dismissed`: a `Flagged` confession returns to `Active`.
+    /// - `Hidden`: the confession becomes `Hidden`.
+    /// - `Deleted`: the confession becomes `Deleted`.
+    ///
+    /// A confession deleted while its case was open stays `Deleted`; resolving
+    /// such a case as `Hidden` panics.
+    ///
+    /// Emits: `("mod_case",)` → `ModerationCaseEvent` with state `Resolved(outcome)`, and
+    /// `("confession_updated", id)` when the confession status changes.
+    pub fn resolve_case(env: Env, caller: Address, confession_id: u64, outcome: CaseOutcome) {
+        access_control::require_admin_or_owner(&env, &caller)
+            .unwrap_or_else(|_| panic!("{}", ContractError::Unauthorized.message()));
+
+        emergency_pause::assert_not_paused(&env)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+
+        let mut case = read_case(&env, confession_id);
+        if matches!(case.state, CaseState::Resolved(_)) {
+            panic!("moderation case is already resolved");
+        }
+
+        let mut confession = read_confession(&env, confession_id);
+
+        let now = env.ledger().timestamp();
+        let old_status = confession.status.clone();
+
+        // Terminal-state guard — a deleted confession is immutable, so its case
+        // can be closed but never brought back as Hidden.
+        if old_status == ConfessionStatus::Deleted && outcome == CaseOutcome::Hidden {
+            panic!("confession is deleted and cannot be updated");
+        }
+
+        let new_status = match outcome {
+            CaseOutcome::Dismissed if old_status == ConfessionStatus::Flagged => {
+                ConfessionStatus::Active
+            }
+            CaseOutcome::Dismissed => old_status.clone(),
+            CaseOutcome::Hidden => ConfessionStatus::Hidden,
+            CaseOutcome::Deleted => ConfessionStatus::Deleted,
+        };
+
+        case.state = CaseState::Resolved(outcome.clone());
+        case.reviewer = Some(caller.clone());
+        case.updated_at = now;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Case(confession_id), &case);
+        extend_persistent(&env, &DataKey::Case(confession_id));
+
+        if new_status != old_status {
+            confession.status = new_status.clone();
+            confession.updated_at = now;
+            write_confession(&env, &confession);
+
+            events::next_confession_nonce(&env, confession_id);
+            let event_topic = Symbol::new(&env, "confession_updated");
+            env.events().publish(
+                (event_topic, confession_id),
+                (old_status, new_status, now),
+            );
+        }
+
+        events::emit_moderation_case(
+            &env,
+            confession_id,
+            caller,
+            CaseState::Resolved(outcome),
+            case.report_count,
+        );
+    }
+
+    /// Get the current moderation case for a confession, if one was opened.
+    pub fn get_case(env: Env, confession_id: u64) -> Option<ModerationCase> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Case(confession_id))
+    }
+
     // ─── Update Status ───
 
     /// Update the status of a confession.
     ///
-    /// Only the author or the contract admin can change status.
+    /// Only the author or the contract admin can change status, and only the
+    /// admin can set `Flagged`/`Hidden` or move a confession out of them.
     ///
     /// Emits: `("confession_updated", id)` → `(old_status, new_status, timestamp)`
     pub fn update_status(
@@ -248,11 +1037,7 @@ impl ConfessionRegistry {
         emergency_pause::assert_not_paused(&env)
             .unwrap_or_else(|err| panic!("{}", err as u32));
 
-        let mut confession: Confession = env
-            .storage()
-            .instance()
-            .get(&DataKey::Confession(id))
-            .expect("confession not found");
+        let mut confession = read_confession(&env, id);
 
         // Terminal-state guard — a deleted confession is immutable.
         // Prevents resurrection (Deleted → Active) and double-delete side effects.
@@ -270,14 +1055,22 @@ impl ConfessionRegistry {
             panic!("unauthorized: only author or admin can update status");
         }
 
+        // Flagging and hiding are moderation states: only an admin may enter
+        // or leave them, so an author cannot undo an auto-flag or outcome.
+        let moderated = |status: &ConfessionStatus| {
+            matches!(status, ConfessionStatus::Flagged | ConfessionStatus::Hidden)
+        };
+        if caller != admin && (moderated(&confession.status) || moderated(&new_status)) {
+            panic!("unauthorized: only admin can set or clear a moderation status");
+        }
+
         let old_status = confession.status.clone();
         confession.status = new_status;
         confession.updated_at = timestamp;
 
-        env.storage()
-            .instance()
-            .set(&DataKey::Confession(id), &confession);
+        write_confession(&env, &confession);
 
+        events::next_confession_nonce(&env, id);
         let event_topic = Symbol::new(&env, "confession_updated");
         env.events().publish(
             (event_topic, id),
@@ -299,11 +1092,7 @@ impl ConfessionRegistry {
         emergency_pause::assert_not_paused(&env)
             .unwrap_or_else(|err| panic!("{}", err as u32));
 
-        let mut confession: Confession = env
-            .storage()
-            .instance()
-            .get(&DataKey::Confession(id))
-            .expect("confession not found");
+        let mut confession = read_confession(&env, id);
 
         // Terminal-state guard — prevents double-delete and misleading updated_at stamps.
         if confession.status == ConfessionStatus::Deleted {
@@ -323,10 +1112,9 @@ impl ConfessionRegistry {
         confession.status = ConfessionStatus::Deleted;
         confession.updated_at = timestamp;
 
-        env.storage()
-            .instance()
-            .set(&DataKey::Confession(id), &confession);
+        write_confession(&env, &confession);
 
+        events::next_confession_nonce(&env, id);
         let event_topic = Symbol::new(&env, "confession_deleted");
         env.events().publish((event_topic, id), (caller, timestamp));
     }
@@ -337,7 +1125,10 @@ impl ConfessionRegistry {
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+    use soroban_sdk::{
+        testutils::{storage::Persistent as _, Address as _, EnvTestConfig, Events, Ledger},
+        Address, BytesN, Env, TryFromVal,
+    };
 
     fn setup() -> (Env, ConfessionRegistryClient<'static>, Address, Address) {
         let env = Env::default();
@@ -432,13 +1223,37 @@ mod test {
         let hash = sample_hash(&env, 30);
 
         let id = client.create_confession(&author, &hash, &1_000);
-        client.update_status(&author, &id, &ConfessionStatus::Flagged, &2_000);
+        client.update_status(&author, &id, &ConfessionStatus::Deleted, &2_000);
 
         let conf = client.get_confession(&id);
-        assert_eq!(conf.status, ConfessionStatus::Flagged);
+        assert_eq!(conf.status, ConfessionStatus::Deleted);
         assert_eq!(conf.updated_at, 2_000);
     }
 
+    #[test]
+    fn test_author_cannot_set_or_clear_moderation_status() {
+        let (env, client, admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 33), &1_000);
+
+        for status in [ConfessionStatus::Flagged, ConfessionStatus::Hidden] {
+            let res = client.try_update_status(&author, &id, &status, &2_000);
+            assert!(res.is_err());
+        }
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Active);
+
+        // An auto-flag stays until an admin clears it.
+        for _ in 0..configurable::config::DEFAULT_THRESHOLD {
+            client.report_confession(&Address::generate(&env), &id, &reason(&env));
+        }
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
+        let res = client.try_update_status(&author, &id, &ConfessionStatus::Active, &3_000);
+        assert!(res.is_err());
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
+
+        client.update_status(&admin, &id, &ConfessionStatus::Active, &4_000);
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Active);
+    }
+
     #[test]
     fn test_update_status_by_admin() {
         let (env, client, admin, author) = setup();
@@ -593,6 +1408,773 @@ mod test {
         client.create_confession(&author, &hash, &2_000);
     }
 
+    #[test]
+    fn test_react_and_summary() {
+        let (env, client, _admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 60), &1_000);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+
+        client.react(&id, &alice, &symbol_short!("like"));
+        client.react(&id, &bob, &symbol_short!("like"));
+        client.react(&id, &carol, &symbol_short!("love"));
+
+        let summary = client.get_reaction_summary(&id);
+        assert_eq!(summary.counts.get(symbol_short!("like")), Some(2));
+        assert_eq!(summary.counts.get(symbol_short!("love")), Some(1));
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.nonce, 3);
+        assert_eq!(client.get_reaction(&id, &alice), Some(symbol_short!("like")));
+    }
+
+    #[test]
+    #[should_panic(expected = "reaction already exists")]
+    fn test_second_reaction_from_same_reactor_rejected() {
+        let (env, client, _admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 61), &1_000);
+        let reactor = Address::generate(&env);
+
+        client.react(&id, &reactor, &symbol_short!("like"));
+        client.react(&id, &reactor, &symbol_short!("love")); // panic
+    }
+
+    #[test]
+    #[should_panic(expected = "reaction type invalid")]
+    fn test_reaction_type_outside_allow_list_rejected() {
+        let (env, client, _admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 62), &1_000);
+        let reactor = Address::generate(&env);
+
+        client.react(&id, &reactor, &symbol_short!("spam")); // panic
+    }
+
+    #[test]
+    fn test_unreact_decrements_and_allows_new_reaction() {
+        let (env, client, _admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 63), &1_000);
+        let reactor = Address::generate(&env);
+
+        client.react(&id, &reactor, &symbol_short!("like"));
+        client.unreact(&id, &reactor);
+
+        let summary = client.get_reaction_summary(&id);
+        assert_eq!(summary.counts.get(symbol_short!("like")), None);
+        assert_eq!(summary.total, 0);
+        assert_eq!(client.get_reaction(&id, &reactor), None);
+
+        client.react(&id, &reactor, &symbol_short!("sad"));
+        let summary = client.get_reaction_summary(&id);
+        assert_eq!(summary.counts.get(symbol_short!("sad")), Some(1));
+        assert_eq!(summary.nonce, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "entity not found")]
+    fn test_unreact_without_reaction_rejected() {
+        let (env, client, _admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 64), &1_000);
+        client.unreact(&id, &Address::generate(&env)); // panic
+    }
+
+    #[test]
+    fn test_admin_replaces_reaction_allow_list() {
+        let (env, client, admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 65), &1_000);
+        let reactor = Address::generate(&env);
+        let types = Vec::from_array(&env, [symbol_short!("fire")]);
+
+        client.set_reaction_types(&admin, &types);
+        assert_eq!(client.get_reaction_types(), types);
+
+        client.react(&id, &reactor, &symbol_short!("fire"));
+        assert!(client
+            .try_react(&id, &Address::generate(&env), &symbol_short!("like"))
+            .is_err());
+
+        let outsider = Address::generate(&env);
+        assert!(client.try_set_reaction_types(&outsider, &types).is_err());
+    }
+
+    #[test]
+    fn test_cannot_react_to_deleted_confession() {
+        let (env, client, _admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 66), &1_000);
+        client.delete_confession(&author, &id, &2_000);
+
+        let res = client.try_react(&id, &Address::generate(&env), &symbol_short!("like"));
+        assert!(res.is_err());
+    }
+
+    fn reason(env: &Env) -> String {
+        String::from_str(env, "abuse")
+    }
+
+    #[test]
+    fn test_reports_group_into_single_case() {
+        let (env, client, _admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 70), &1_000);
+
+        client.report_confession(&Address::generate(&env), &id, &reason(&env));
+        client.report_confession(&Address::generate(&env), &id, &reason(&env));
+
+        let case = client.get_case(&id).unwrap();
+        assert_eq!(case.state, CaseState::Open);
+        assert_eq!(case.report_count, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "cooldown period not elapsed")]
+    fn test_duplicate_report_rejected() {
+        let (env, client, _admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 71), &1_000);
+        let reporter = Address::generate(&env);
+
+        client.report_confession(&reporter, &id, &reason(&env));
+        client.report_confession(&reporter, &id, &reason(&env)); // panic
+    }
+
+    #[test]
+    fn test_review_then_resolve_hidden() {
+        let (env, client, admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 72), &1_000);
+        client.report_confession(&Address::generate(&env), &id, &reason(&env));
+
+        client.review_case(&admin, &id);
+        let case = client.get_case(&id).unwrap();
+        assert_eq!(case.state, CaseState::UnderReview);
+        assert_eq!(case.reviewer, Some(admin.clone()));
+
+        client.resolve_case(&admin, &id, &CaseOutcome::Hidden);
+        let case = client.get_case(&id).unwrap();
+        assert_eq!(case.state, CaseState::Resolved(CaseOutcome::Hidden));
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Hidden);
+
+        // The author cannot undo a moderation outcome.
+        let res = client.try_update_status(&author, &id, &ConfessionStatus::Active, &3_000);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_resolve_deleted_and_dismissed() {
+        let (env, client, admin, author) = setup();
+        let deleted = client.create_confession(&author, &sample_hash(&env, 73), &1_000);
+        let dismissed = client.create_confession(&author, &sample_hash(&env, 74), &1_000);
+        client.report_confession(&Address::generate(&env), &deleted, &reason(&env));
+        client.report_confession(&Address::generate(&env), &dismissed, &reason(&env));
+        client.update_status(&admin, &dismissed, &ConfessionStatus::Flagged, &2_000);
+
+        client.resolve_case(&admin, &deleted, &CaseOutcome::Deleted);
+        client.resolve_case(&admin, &dismissed, &CaseOutcome::Dismissed);
+
+        assert_eq!(client.get_confession(&deleted).status, ConfessionStatus::Deleted);
+        assert_eq!(client.get_confession(&dismissed).status, ConfessionStatus::Active);
+    }
+
+    #[test]
+    fn test_resolve_hidden_cannot_revive_deleted() {
+        let (env, client, admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 77), &1_000);
+        client.report_confession(&Address::generate(&env), &id, &reason(&env));
+        client.delete_confession(&author, &id, &2_000);
+
+        let res = client.try_resolve_case(&admin, &id, &CaseOutcome::Hidden);
+        assert!(res.is_err());
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Deleted);
+
+        // The case can still be closed without touching the confession.
+        client.resolve_case(&admin, &id, &CaseOutcome::Dismissed);
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Deleted);
+    }
+
+    #[test]
+    fn test_case_transitions_blocked_while_paused() {
+        let (env, client, admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 78), &1_000);
+        client.report_confession(&Address::generate(&env), &id, &reason(&env));
+
+        let pause = client.gov_propose(&admin, &governance::model::CriticalAction::Pause);
+        client.gov_approve(&admin, &pause);
+        client.gov_execute(&admin, &pause);
+
+        assert!(client.try_review_case(&admin, &id).is_err());
+        assert!(client.try_resolve_case(&admin, &id, &CaseOutcome::Hidden).is_err());
+        assert_eq!(client.get_case(&id).unwrap().state, CaseState::Open);
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Active);
+    }
+
+    #[test]
+    fn test_resolve_requires_admin_and_open_case() {
+        let (env, client, admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 75), &1_000);
+
+        // No case yet.
+        assert!(client.try_resolve_case(&admin, &id, &CaseOutcome::Hidden).is_err());
+
+        client.report_confession(&Address::generate(&env), &id, &reason(&env));
+        assert!(client.try_resolve_case(&author, &id, &CaseOutcome::Hidden).is_err());
+        assert!(client.try_review_case(&author, &id).is_err());
+
+        client.resolve_case(&admin, &id, &CaseOutcome::Dismissed);
+        assert!(client.try_resolve_case(&admin, &id, &CaseOutcome::Hidden).is_err());
+    }
+
+    #[test]
+    fn test_report_after_resolution_opens_new_case() {
+        let (env, client, admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 76), &1_000);
+        client.report_confession(&Address::generate(&env), &id, &reason(&env));
+        client.resolve_case(&admin, &id, &CaseOutcome::Dismissed);
+
+        client.report_confession(&Address::generate(&env), &id, &reason(&env));
+        let case = client.get_case(&id).unwrap();
+        assert_eq!(case.state, CaseState::Open);
+        assert_eq!(case.report_count, 1);
+    }
+
+    #[test]
+    fn test_auto_flag_at_threshold() {
+        let (env, client, _admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 80), &1_000);
+        let threshold = configurable::config::DEFAULT_THRESHOLD;
+
+        for _ in 0..threshold - 1 {
+            client.report_confession(&Address::generate(&env), &id, &reason(&env));
+        }
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Active);
+
+        client.report_confession(&Address::generate(&env), &id, &reason(&env));
+        let flagged = env.events().all().iter().any(|(_, topics, _)| {
+            topics.len() == 3
+                && Symbol::try_from_val(&env, &topics.get(2).unwrap())
+                    == Ok(symbol_short!("auto_flag"))
+        });
+        assert!(flagged);
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
+    }
+
+    #[test]
+    fn test_dismissed_case_resets_auto_flag_count() {
+        let (env, client, admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 81), &1_000);
+        let threshold = configurable::config::DEFAULT_THRESHOLD;
+
+        for _ in 0..threshold {
+            client.report_confession(&Address::generate(&env), &id, &reason(&env));
+        }
+        client.resolve_case(&admin, &id, &CaseOutcome::Dismissed);
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Active);
+
+        client.report_confession(&Address::generate(&env), &id, &reason(&env));
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Active);
+    }
+
+    #[test]
+    fn test_config_defaults_and_update() {
+        let (env, client, admin, _author) = setup();
+
+        let config = client.get_config();
+        assert_eq!(config.threshold, configurable::config::DEFAULT_THRESHOLD);
+        assert_eq!(config.last_update, None);
+
+        env.ledger().set_timestamp(10_000);
+        let updated = client.update_config(&admin, &2_000, &2, &7_200);
+        assert_eq!(updated, client.get_config());
+        assert_eq!(updated.limits, 2_000);
+        assert_eq!(updated.threshold, 2);
+        assert_eq!(updated.window, 7_200);
+        assert_eq!(updated.last_update, Some(10_000));
+    }
+
+    #[test]
+    fn test_config_update_emits_old_and_new_values() {
+        let (env, client, admin, _author) = setup();
+
+        client.update_config(&admin, &2_000, &2, &7_200);
+
+        let (_, _, data) = env.events().all().last().unwrap();
+        let values: (u32, u32, u32, u32, u32, u32) = TryFromVal::try_from_val(&env, &data).unwrap();
+        assert_eq!(values, (1_000, 5, 3_600, 2_000, 2, 7_200));
+    }
+
+    #[test]
+    #[should_panic(expected = "unauthorized: not owner")]
+    fn test_config_update_by_non_owner_rejected() {
+        let (env, client, _admin, _author) = setup();
+        client.update_config(&Address::generate(&env), &2_000, &2, &7_200); // panic
+    }
+
+    #[test]
+    #[should_panic(expected = "threshold exceed max")]
+    fn test_config_update_out_of_bounds_rejected() {
+        let (_env, client, admin, _author) = setup();
+        client.update_config(&admin, &2_000, &1_001, &7_200); // panic
+    }
+
+    #[test]
+    fn test_config_update_cooldown() {
+        let (env, client, admin, _author) = setup();
+
+        env.ledger().set_timestamp(10_000);
+        client.update_config(&admin, &2_000, &2, &7_200);
+
+        env.ledger().set_timestamp(10_000 + 3_599);
+        assert!(client.try_update_config(&admin, &3_000, &3, &7_200).is_err());
+
+        env.ledger().set_timestamp(10_000 + 3_600);
+        client.update_config(&admin, &3_000, &3, &7_200);
+        assert_eq!(client.get_config().threshold, 3);
+    }
+
+    #[test]
+    fn test_config_back_to_back_updates_at_time_zero() {
+        let (_env, client, admin, _author) = setup();
+
+        // The first update lands at ledger time 0; the cooldown still applies.
+        client.update_config(&admin, &2_000, &2, &7_200);
+        assert!(client.try_update_config(&admin, &3_000, &3, &7_200).is_err());
+        assert_eq!(client.get_config().threshold, 2);
+    }
+
+    #[test]
+    fn test_updated_threshold_drives_auto_flag() {
+        let (env, client, admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 82), &1_000);
+
+        client.update_config(&admin, &1_000, &1, &3_600);
+        client.report_confession(&Address::generate(&env), &id, &reason(&env));
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
+    }
+
+    /// Build a registry whose ledger already holds `existing` confessions.
+    ///
+    /// The records are written straight into a ledger snapshot so the host
+    /// only loads the entries each call touches, as on a real network.
+    fn setup_with_existing(
+        existing: u64,
+    ) -> (Env, ConfessionRegistryClient<'static>, Address, Address) {
+        use soroban_sdk::xdr::{
+            ContractDataDurability, ContractDataEntry, ExtensionPoint, LedgerEntry,
+            LedgerEntryData, LedgerEntryExt, LedgerKey, LedgerKeyContractData, ScAddress, ScVal,
+        };
+
+        let mut base = Env::default();
+        base.set_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        let contract_id = base.register(ConfessionRegistry, ());
+        let holder = Address::generate(&base);
+
+        let mut snapshot = base.to_ledger_snapshot();
+        let contract = ScAddress::from(&contract_id);
+        let live_until = snapshot.sequence_number + PERSISTENT_TTL_EXTEND_TO;
+        let mut push = |key: ScVal, val: ScVal| {
+            let ledger_key = LedgerKey::ContractData(LedgerKeyContractData {
+                contract: contract.clone(),
+                key: key.clone(),
+                durability: ContractDataDurability::Persistent,
+            });
+            let entry = LedgerEntry {
+                last_modified_ledger_seq: 0,
+                data: LedgerEntryData::ContractData(ContractDataEntry {
+                    ext: ExtensionPoint::V0,
+                    contract: contract.clone(),
+                    key,
+                    durability: ContractDataDurability::Persistent,
+                    val,
+                }),
+                ext: LedgerEntryExt::V0,
+            };
+            snapshot
+                .ledger_entries
+                .push((ledger_key.into(), (entry.into(), Some(live_until))));
+        };
+
+        // Seeded IDs sit far above the ones the test allocates.
+        for id in 1_000_000..1_000_000 + existing {
+            let mut bytes = [0xAAu8; 32];
+            bytes[..8].copy_from_slice(&id.to_be_bytes());
+            let content_hash = BytesN::from_array(&base, &bytes);
+            let confession = Confession {
+                id,
+                author: holder.clone(),
+                content_hash: content_hash.clone(),
+                created_at: id,
+                updated_at: 0,
+                status: ConfessionStatus::Active,
+            };
+            push(
+                ScVal::try_from(&DataKey::Confession(id)).unwrap(),
+                ScVal::try_from(&confession).unwrap(),
+            );
+            push(
+                ScVal::try_from(&DataKey::HashIndex(content_hash)).unwrap(),
+                ScVal::try_from(&id).unwrap(),
+            );
+        }
+
+        let mut env = Env::from_ledger_snapshot(snapshot);
+        // Writing a 10k-entry snapshot to disk would dominate the test run.
+        env.set_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        env.mock_all_auths();
+        let contract_id = Address::try_from_val(&env, &contract).unwrap();
+        env.register_at(&contract_id, ConfessionRegistry, ());
+        let client = ConfessionRegistryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let author = Address::generate(&env);
+        client.initialize(&admin);
+
+        (env, client, admin, author)
+    }
+
+    /// Resources used by create, read, update and bump on a registry already
+    /// holding `existing` confessions.
+    fn per_call_resources(existing: u64) -> [(i64, u32, u32); 4] {
+        let (env, client, admin, author) = setup_with_existing(existing);
+        let usage = || {
+            let r = env.cost_estimate().resources();
+            (r.instructions, r.memory_read_entries, r.write_bytes)
+        };
+
+        let id = client.create_confession(&author, &sample_hash(&env, 90), &1_000);
+        let create = usage();
+
+        client.get_confession(&id);
+        let read = usage();
+
+        client.update_status(&admin, &id, &ConfessionStatus::Flagged, &2_000);
+        let update = usage();
+
+        client.bump_confession_ttl(&id);
+        let bump = usage();
+
+        [create, read, update, bump]
+    }
+
+    #[test]
+    fn test_per_call_cost_flat_with_10k_confessions() {
+        let small = per_call_resources(10);
+        let large = per_call_resources(10_000);
+
+        for (s, l) in small.iter().zip(large.iter()) {
+            // Same entries touched and bytes written; allow 10% instruction noise.
+            assert_eq!((s.1, s.2), (l.1, l.2));
+            assert!(l.0 <= s.0 + s.0 / 10, "cost grew from {} to {}", s.0, l.0);
+        }
+    }
+
+    #[test]
+    fn test_records_live_in_persistent_storage_with_ttl() {
+        let (env, client, _admin, author) = setup();
+        let hash = sample_hash(&env, 91);
+        let id = client.create_confession(&author, &hash, &1_000);
+
+        env.as_contract(&client.address, || {
+            assert!(!env.storage().instance().has(&DataKey::Confession(id)));
+            assert!(env.storage().persistent().has(&DataKey::Confession(id)));
+            assert!(env.storage().persistent().has(&DataKey::HashIndex(hash.clone())));
+            assert!(env
+                .storage()
+                .persistent()
+                .has(&DataKey::AuthorChunk(author.clone(), 0)));
+
+            let ttl = env.storage().persistent().get_ttl(&DataKey::Confession(id));
+            assert!(ttl >= PERSISTENT_TTL_THRESHOLD);
+        });
+    }
+
+    #[test]
+    fn test_bump_confession_ttl_extends_expiring_entry() {
+        let (env, client, _admin, author) = setup();
+        let hash = sample_hash(&env, 92);
+        let id = client.create_confession(&author, &hash, &1_000);
+
+        let ttl_before = env.as_contract(&client.address, || {
+            env.storage().persistent().get_ttl(&DataKey::Confession(id))
+        });
+        // Advance until the entry is below the extension threshold.
+        let seq = env.ledger().sequence();
+        env.ledger()
+            .set_sequence_number(seq + ttl_before - PERSISTENT_TTL_THRESHOLD + 1);
+
+        client.bump_confession_ttl(&id);
+
+        env.as_contract(&client.address, || {
+            let ttl = env.storage().persistent().get_ttl(&DataKey::Confession(id));
+            assert_eq!(ttl, PERSISTENT_TTL_EXTEND_TO);
+            let hash_ttl = env.storage().persistent().get_ttl(&DataKey::HashIndex(hash.clone()));
+            assert_eq!(hash_ttl, PERSISTENT_TTL_EXTEND_TO);
+        });
+    }
+
+    #[test]
+    fn test_index_entries_and_report_markers_get_ttl() {
+        let (env, client, _admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 93), &1_000);
+        let reporter = Address::generate(&env);
+        client.report_confession(&reporter, &id, &reason(&env));
+
+        let marker = report::ReportKey::Submitted(reporter.clone(), id);
+        let (entry_key, entry_ttl) = env.as_contract(&client.address, || {
+            let keys: Vec<(u64, u64)> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::IndexChunk(0))
+                .unwrap();
+            let entry_key = ConfessionKey::Index(keys.get(0).unwrap());
+            let marker_ttl = env.storage().persistent().get_ttl(&marker);
+            assert!(marker_ttl >= PERSISTENT_TTL_THRESHOLD);
+            let entry_ttl = env.storage().persistent().get_ttl(&entry_key);
+            (entry_key, entry_ttl)
+        });
+
+        // Walking the index extends an entry that is about to expire.
+        let seq = env.ledger().sequence();
+        env.ledger()
+            .set_sequence_number(seq + entry_ttl - PERSISTENT_TTL_THRESHOLD + 1);
+        client.list_confessions(&None, &1, &SnapshotOrder::Ascending);
+
+        env.as_contract(&client.address, || {
+            let ttl = env.storage().persistent().get_ttl(&entry_key);
+            assert_eq!(ttl, PERSISTENT_TTL_EXTEND_TO);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "confession not found")]
+    fn test_bump_ttl_for_missing_confession_rejected() {
+        let (_env, client, _admin, _author) = setup();
+        client.bump_confession_ttl(&999);
+    }
+
+    fn create_many(env: &Env, client: &ConfessionRegistryClient, author: &Address, n: u64) {
+        for i in 0..n {
+            let mut bytes = [0x55u8; 32];
+            bytes[..8].copy_from_slice(&i.to_be_bytes());
+            client.create_confession(author, &BytesN::from_array(env, &bytes), &i);
+        }
+    }
+
+    #[test]
+    fn test_author_page_walks_all_chunks() {
+        let (env, client, _admin, author) = setup();
+        create_many(&env, &client, &author, 120);
+
+        let mut seen = Vec::<u64>::new(&env);
+        let mut cursor = None;
+        loop {
+            let page = client.get_author_confessions_page(&author, &cursor, &50, &None);
+            for confession in page.items.iter() {
+                assert_eq!(confession.author, author);
+                seen.push_back(confession.id);
+            }
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        assert_eq!(seen.len(), 120);
+        for (i, id) in seen.iter().enumerate() {
+            assert_eq!(id, i as u64 + 1);
+        }
+        assert_eq!(client.get_author_confessions(&author), seen);
+    }
+
+    #[test]
+    fn test_author_index_is_chunked() {
+        let (env, client, _admin, author) = setup();
+        create_many(&env, &client, &author, 120);
+
+        env.as_contract(&client.address, || {
+            for chunk in 0..3 {
+                let ids: Vec<u64> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::AuthorChunk(author.clone(), chunk))
+                    .unwrap();
+                assert!(ids.len() <= AUTHOR_CHUNK_SIZE);
+            }
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&DataKey::AuthorChunk(author.clone(), 3)));
+        });
+    }
+
+    #[test]
+    fn test_author_page_filters_by_status() {
+        let (env, client, admin, author) = setup();
+        create_many(&env, &client, &author, 10);
+        for id in [2u64, 5, 9] {
+            client.update_status(&admin, &id, &ConfessionStatus::Flagged, &1);
+        }
+
+        let page = client.get_author_confessions_page(
+            &author,
+            &None,
+            &10,
+            &Some(ConfessionStatus::Flagged),
+        );
+        assert_eq!(page.items.len(), 3);
+        assert_eq!(page.items.get(1).unwrap().id, 5);
+        assert_eq!(page.next_cursor, None);
+
+        let page = client.get_author_confessions_page(&author, &None, &2, &None);
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.next_cursor, Some(2));
+    }
+
+    #[test]
+    fn test_author_page_bounds() {
+        let (_env, client, _admin, author) = setup();
+
+        let page = client.get_author_confessions_page(&author, &None, &10, &None);
+        assert_eq!(page.items.len(), 0);
+        assert_eq!(page.next_cursor, None);
+
+        assert!(client
+            .try_get_author_confessions_page(&author, &None, &0, &None)
+            .is_err());
+        assert!(client
+            .try_get_author_confessions_page(&author, &None, &(MAX_PAGE_SIZE + 1), &None)
+            .is_err());
+    }
+
+    fn collect_feed(client: &ConfessionRegistryClient, env: &Env, limit: u32, order: SnapshotOrder) -> Vec<u64> {
+        let mut ids = Vec::new(env);
+        let mut cursor = None;
+        loop {
+            let page = client.list_confessions(&cursor, &limit, &order);
+            assert!(page.items.len() <= limit);
+            for confession in page.items.iter() {
+                ids.push_back(confession.id);
+            }
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        ids
+    }
+
+    #[test]
+    fn test_list_confessions_ascending_and_descending() {
+        let (env, client, _admin, author) = setup();
+        let other = Address::generate(&env);
+        create_many(&env, &client, &author, 7);
+        client.create_confession(&other, &sample_hash(&env, 99), &7);
+
+        let asc = collect_feed(&client, &env, 3, SnapshotOrder::Ascending);
+        assert_eq!(asc, Vec::from_array(&env, [1, 2, 3, 4, 5, 6, 7, 8]));
+
+        let desc = collect_feed(&client, &env, 3, SnapshotOrder::Descending);
+        assert_eq!(desc, Vec::from_array(&env, [8, 7, 6, 5, 4, 3, 2, 1]));
+    }
+
+    #[test]
+    fn test_list_confessions_cursor_and_bounds() {
+        let (env, client, _admin, author) = setup();
+
+        let empty = client.list_confessions(&None, &10, &SnapshotOrder::Descending);
+        assert_eq!(empty.items.len(), 0);
+        assert_eq!(empty.next_cursor, None);
+
+        create_many(&env, &client, &author, 5);
+        client.delete_confession(&author, &2, &10);
+
+        let page = client.list_confessions(&Some(2), &2, &SnapshotOrder::Ascending);
+        assert_eq!(page.items.get(0).unwrap().status, ConfessionStatus::Deleted);
+        assert_eq!(page.items.get(1).unwrap().id, 3);
+        assert_eq!(page.next_cursor, Some(4));
+
+        let page = client.list_confessions(&Some(2), &5, &SnapshotOrder::Descending);
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.next_cursor, None);
+
+        assert!(client
+            .try_list_confessions(&None, &0, &SnapshotOrder::Ascending)
+            .is_err());
+        assert!(client
+            .try_list_confessions(&None, &(MAX_PAGE_SIZE + 1), &SnapshotOrder::Ascending)
+            .is_err());
+    }
+
+    #[test]
+    fn test_list_confessions_skips_index_gaps() {
+        let (env, client, _admin, author) = setup();
+        create_many(&env, &client, &author, 5);
+
+        // Drop the index entry for confession 3 to leave a gap.
+        env.as_contract(&client.address, || {
+            let keys = read_index_chunk(&env, 0);
+            env.storage()
+                .persistent()
+                .remove(&ConfessionKey::Index(keys.get(2).unwrap()));
+        });
+
+        let asc = collect_feed(&client, &env, 2, SnapshotOrder::Ascending);
+        assert_eq!(asc, Vec::from_array(&env, [1, 2, 4, 5]));
+        let desc = collect_feed(&client, &env, 2, SnapshotOrder::Descending);
+        assert_eq!(desc, Vec::from_array(&env, [5, 4, 2, 1]));
+
+        let page = client.list_confessions(&Some(2), &2, &SnapshotOrder::Ascending);
+        assert_eq!(page.items.get(1).unwrap().id, 4);
+        assert_eq!(page.next_cursor, Some(5));
+    }
+
+    #[test]
+    fn test_checkpoint_tracks_live_state() {
+        let (env, client, admin, author) = setup();
+
+        let empty = client.get_checkpoint();
+        assert_eq!(empty.total_confessions, 0);
+        assert_eq!(empty.latest_confession_id, 0);
+        assert_eq!(empty.version_marker, checkpoint::CHECKPOINT_VERSION);
+        assert_eq!(empty.event_nonces.len(), events::EVENT_STREAMS.len() as u32);
+        assert_eq!(empty.event_nonces.get(events::REACTION_EVENT), Some(0));
+
+        create_many(&env, &client, &author, 3);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        client.react(&1, &alice, &symbol_short!("like"));
+        client.react(&2, &alice, &symbol_short!("love"));
+        client.react(&2, &bob, &symbol_short!("like"));
+        client.unreact(&2, &alice);
+        client.report_confession(&alice, &3, &reason(&env));
+        client.report_confession(&bob, &3, &reason(&env));
+        client.review_case(&admin, &3);
+
+        let summary = client.get_checkpoint();
+        assert_eq!(summary.total_confessions, 3);
+        assert_eq!(summary.latest_confession_id, 3);
+        assert_eq!(summary.total_reactions, 2);
+        assert_eq!(summary.latest_reaction_id, 3);
+        assert_eq!(summary.total_reports, 2);
+        assert_eq!(summary.latest_report_id, 2);
+        assert_eq!(summary.event_nonces.get(events::REACTION_EVENT), Some(4));
+        assert_eq!(summary.event_nonces.get(events::REPORT_EVENT), Some(2));
+        assert_eq!(summary.event_nonces.get(events::MODERATION_EVENT), Some(3));
+        assert_eq!(summary.ledger_sequence, env.ledger().sequence());
+    }
+
+    #[test]
+    fn test_checkpoint_counts_confession_lifecycle_events() {
+        let (env, client, _admin, author) = setup();
+
+        create_many(&env, &client, &author, 3);
+        let summary = client.get_checkpoint();
+        assert_eq!(summary.event_nonces.get(events::CONFESSION_EVENT), Some(3));
+
+        client.update_status(&author, &1, &ConfessionStatus::Deleted, &2_000);
+        client.delete_confession(&author, &2, &3_000);
+
+        let summary = client.get_checkpoint();
+        assert_eq!(summary.event_nonces.get(events::CONFESSION_EVENT), Some(5));
+    }
+
     #[test]
     #[should_panic(expected = "already initialized")]
     fn test_double_initialization() {
@@ -1,48 +1,111 @@
-use soroban_sdk::{contractimpl, Env, BytesN};
-use crate::configurable::access_control::Admin;
+use soroban_sdk::{contracttype, Address, Env};
+use crate::access_control::require_owner;
 use crate::configurable::events::emit_config_update;
 
+pub const DEFAULT_LIMITS: u32 = 1_000;
+/// Distinct reporters needed before a confession is auto-flagged (0 disables).
+pub const DEFAULT_THRESHOLD: u32 = 5;
+pub const DEFAULT_WINDOW: u32 = 3_600;
+
+pub const MAX_LIMITS: u32 = 10_000;
+pub const MAX_THRESHOLD: u32 = 1_000;
+pub const MAX_WINDOW: u32 = 86_400;
+/// Minimum seconds between two updates.
+pub const UPDATE_COOLDOWN: u64 = 3_600;
+
+#[contracttype]
 #[derive(Clone)]
+enum ConfigKey {
+    Config,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Config {
     pub limits: u32,
     pub threshold: u32,
     pub window: u32,
-    pub last_update: u64,
+    /// Ledger timestamp of the last update (`None` if never updated).
+    pub last_update: Option<u64>,
+}
+
+fn check_bounds(limits: u32, threshold: u32, window: u32) {
+    assert!(limits <= MAX_LIMITS, "limits exceed max");
+    assert!(threshold <= MAX_THRESHOLD, "threshold exceed max");
+    assert!(window <= MAX_WINDOW, "window exceed max");
 }
 
-#[contractimpl]
 impl Config {
-    pub fn init(env: Env, limits: u32, threshold: u32, window: u32) -> Config {
-        Config {
+    /// Store the initial configuration. Intended for contract initialization;
+    /// later changes go through `update`.
+    pub fn init(env: &Env, limits: u32, threshold: u32, window: u32) -> Config {
+        check_bounds(limits, threshold, window);
+
+        let config = Config {
             limits,
             threshold,
             window,
-            last_update: 0,
-        }
+            last_update: None,
+        };
+        env.storage().instance().set(&ConfigKey::Config, &config);
+        config
+    }
+
+    /// Stored configuration, or the defaults if none has been stored yet.
+    pub fn load(env: &Env) -> Config {
+        env.storage()
+            .instance()
+            .get(&ConfigKey::Config)
+            .unwrap_or(Config {
+                limits: DEFAULT_LIMITS,
+                threshold: DEFAULT_THRESHOLD,
+                window: DEFAULT_WINDOW,
+                last_update: None,
+            })
     }
 
+    /// Replace the stored configuration. Owner only, bounded, and rate-limited
+    /// to one update per `UPDATE_COOLDOWN`.
+    ///
+    /// Emits: `("ConfigUpdate",)` → `(old_limits, old_threshold, old_window,
+    /// new_limits, new_threshold, new_window)`
     pub fn update(
-        &mut self,
-        env: Env,
-        admin: BytesN<32>,
+        env: &Env,
+        caller: &Address,
         new_limits: u32,
         new_threshold: u32,
         new_window: u32,
-    ) {
-        Admin::require_admin(&env, &admin);
+    ) -> Config {
+        require_owner(env, caller).unwrap_or_else(|_| panic!("unauthorized: not owner"));
 
-        assert!(new_limits <= 10_000, "limits exceed max");
-        assert!(new_threshold <= 1_000, "threshold exceed max");
-        assert!(new_window <= 86_400, "window exceed max");
+        check_bounds(new_limits, new_threshold, new_window);
 
+        let old = Self::load(env);
         let now = env.ledger().timestamp();
-        assert!(now - self.last_update >= 3600, "cooldown not elapsed");
+        if let Some(last_update) = old.last_update {
+            assert!(
+                now.saturating_sub(last_update) >= UPDATE_COOLDOWN,
+                "cooldown not elapsed"
+            );
+        }
 
-        emit_config_update(&env, self.limits, self.threshold, self.window, new_limits, new_threshold, new_window);
+        emit_config_update(
+            env,
+            old.limits,
+            old.threshold,
+            old.window,
+            new_limits,
+            new_threshold,
+            new_window,
+        );
 
-        self.limits = new_limits;
-        self.threshold = new_threshold;
-        self.window = new_window;
-        self.last_update = now;
+        let config = Config {
+            limits: new_limits,
+            threshold: new_threshold,
+            window: new_window,
+            last_update: Some(now),
+        };
+        env.storage().instance().set(&ConfigKey::Config, &config);
+        config
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,5 @@
 pub mod config;
-pub mod access_control;
 pub mod events;
 
 pub use config::Config;
-pub use access_control::Admin;
-pub use events::emit_config_update;
\ No newline at end of file
+pub use events::emit_config_update;
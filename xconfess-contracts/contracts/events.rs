@@ -8,9 +8,24 @@ pub const EVENT_VERSION_V1: u32 = 1;
 /// Stable discriminators (NEVER CHANGE)
 pub const CONFESSION_EVENT: Symbol = symbol_short!("confess");
 pub const REACTION_EVENT: Symbol = symbol_short!("react");
+pub const REACTION_REMOVED_EVENT: Symbol = symbol_short!("unreact");
 pub const REPORT_EVENT: Symbol = symbol_short!("report");
 pub const ROLE_EVENT: Symbol = symbol_short!("role");
 pub const BADGE_EVENT: Symbol = symbol_short!("badge");
+pub const MODERATION_EVENT: Symbol = symbol_short!("mod_case");
+/// Aggregate stream for all governance event streams.
+pub const GOVERNANCE_EVENT: Symbol = symbol_short!("gov");
+
+/// Every nonce stream with a contract-wide total, see `latest_stream_nonce`.
+pub const EVENT_STREAMS: [Symbol; 7] = [
+    CONFESSION_EVENT,
+    REACTION_EVENT,
+    REPORT_EVENT,
+    ROLE_EVENT,
+    BADGE_EVENT,
+    MODERATION_EVENT,
+    GOVERNANCE_EVENT,
+];
 
 /// ===========================================
 /// GOVERNANCE METADATA LIMITS
@@ -93,24 +108,115 @@ enum EventNonceKey {
     Role(Address, Symbol),
     Governance(Symbol),
     Badge(u64),
+    Moderation(u64),
+    /// Contract-wide total across every key of one stream.
+    Stream(Symbol),
 }
 
+impl EventNonceKey {
+    fn stream(&self) -> Symbol {
+        match self {
+            EventNonceKey::Confession(_) => CONFESSION_EVENT,
+            EventNonceKey::Reaction(_) => REACTION_EVENT,
+            EventNonceKey::Report(_) => REPORT_EVENT,
+            EventNonceKey::Role(_, _) => ROLE_EVENT,
+            EventNonceKey::Governance(_) => GOVERNANCE_EVENT,
+            EventNonceKey::Badge(_) => BADGE_EVENT,
+            EventNonceKey::Moderation(_) => MODERATION_EVENT,
+            EventNonceKey::Stream(stream) => stream.clone(),
+        }
+    }
+}
+
+/// Persistent storage key for a nonce. Wrapping `EventNonceKey` keeps it
+/// from aliasing a host contract's own keys, e.g. the registry's
+/// `DataKey::Confession(u64)`, which encodes the same as
+/// `EventNonceKey::Confession(u64)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum EventStorageKey {
+    Nonce(EventNonceKey),
+}
+
+/// Nonce entries are extended to ~30 days once they fall below ~7 days
+/// (at ~5s per ledger).
+const NONCE_TTL_THRESHOLD: u32 = 17_280 * 7;
+const NONCE_TTL_EXTEND_TO: u32 = 17_280 * 30;
+
 fn read_nonce(env: &Env, key: &EventNonceKey) -> u64 {
-    env.storage().instance().get(key).unwrap_or(0u64)
+    let key = EventStorageKey::Nonce(key.clone());
+    env.storage().persistent().get(&key).unwrap_or(0u64)
+}
+
+fn write_nonce(env: &Env, key: &EventNonceKey, value: u64) {
+    let key = EventStorageKey::Nonce(key.clone());
+    env.storage().persistent().set(&key, &value);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, NONCE_TTL_THRESHOLD, NONCE_TTL_EXTEND_TO);
 }
 
 fn bump_nonce(env: &Env, key: EventNonceKey) -> u64 {
     let next = read_nonce(env, &key)
         .checked_add(1)
         .expect("event nonce overflow");
-    env.storage().instance().set(&key, &next);
+    write_nonce(env, &key, next);
+
+    let stream_key = EventNonceKey::Stream(key.stream());
+    let total = read_nonce(env, &stream_key)
+        .checked_add(1)
+        .expect("event nonce overflow");
+    write_nonce(env, &stream_key, total);
+
     next
 }
 
+/// Number of events emitted on `stream` (one of `EVENT_STREAMS`) across all
+/// of its keys.
+pub fn latest_stream_nonce(env: &Env, stream: Symbol) -> u64 {
+    read_nonce(env, &EventNonceKey::Stream(stream))
+}
+
+pub fn latest_confession_nonce(env: &Env, confession_id: u64) -> u64 {
+    read_nonce(env, &EventNonceKey::Confession(confession_id))
+}
+
+pub fn latest_reaction_nonce(env: &Env, confession_id: u64) -> u64 {
+    read_nonce(env, &EventNonceKey::Reaction(confession_id))
+}
+
+pub fn latest_report_nonce(env: &Env, confession_id: u64) -> u64 {
+    read_nonce(env, &EventNonceKey::Report(confession_id))
+}
+
+pub fn latest_role_nonce(env: &Env, user: Address, role: Symbol) -> u64 {
+    read_nonce(env, &EventNonceKey::Role(user, role))
+}
+
+pub fn latest_badge_nonce(env: &Env, badge_id: u64) -> u64 {
+    read_nonce(env, &EventNonceKey::Badge(badge_id))
+}
+
+pub fn latest_moderation_nonce(env: &Env, confession_id: u64) -> u64 {
+    read_nonce(env, &EventNonceKey::Moderation(confession_id))
+}
+
 pub fn latest_governance_nonce(env: &Env, stream: Symbol) -> u64 {
     read_nonce(env, &EventNonceKey::Governance(stream))
 }
 
+/// Bump the confession nonce, for confession lifecycle events published
+/// outside `emit_confession`.
+pub fn next_confession_nonce(env: &Env, confession_id: u64) -> u64 {
+    bump_nonce(env, EventNonceKey::Confession(confession_id))
+}
+
+/// Bump the report nonce for a confession, for report events published
+/// outside `emit_report`.
+pub fn next_report_nonce(env: &Env, confession_id: u64) -> u64 {
+    bump_nonce(env, EventNonceKey::Report(confession_id))
+}
+
 pub fn next_governance_nonce(env: &Env, stream: Symbol) -> u64 {
     bump_nonce(env, EventNonceKey::Governance(stream))
 }
@@ -222,6 +328,30 @@ pub fn emit_reaction(
     env.events().publish((REACTION_EVENT,), payload);
 }
 
+/// Shares the per-confession reaction nonce with `emit_reaction` so indexers
+/// can replay adds and removals in order.
+pub fn emit_reaction_removed(
+    env: &Env,
+    confession_id: u64,
+    reactor: Address,
+    reaction_type: Symbol,
+    correlation_id: Option<Symbol>,
+) {
+    let nonce = bump_nonce(env, EventNonceKey::Reaction(confession_id));
+
+    let payload = ReactionEvent {
+        event_version: EVENT_VERSION_V1,
+        confession_id,
+        reactor,
+        reaction_type,
+        nonce,
+        timestamp: env.ledger().timestamp(),
+        correlation_id,
+    };
+
+    env.events().publish((REACTION_REMOVED_EVENT,), payload);
+}
+
 /// ===========================================
 /// REPORT EVENT
 /// ===========================================
@@ -340,6 +470,59 @@ pub fn emit_badge_event(
     env.events().publish((BADGE_EVENT,), payload);
 }
 
+/// ===========================================
+/// MODERATION CASE EVENT
+/// ===========================================
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CaseState {
+    Open,
+    UnderReview,
+    Resolved(CaseOutcome),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CaseOutcome {
+    Dismissed,
+    Hidden,
+    Deleted,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModerationCaseEvent {
+    pub event_version: u32,
+    pub confession_id: u64,
+    pub actor: Address,
+    pub state: CaseState,
+    pub report_count: u32,
+    pub nonce: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_moderation_case(
+    env: &Env,
+    confession_id: u64,
+    actor: Address,
+    state: CaseState,
+    report_count: u32,
+) {
+    let nonce = bump_nonce(env, EventNonceKey::Moderation(confession_id));
+
+    let payload = ModerationCaseEvent {
+        event_version: EVENT_VERSION_V1,
+        confession_id,
+        actor,
+        state,
+        report_count,
+        nonce,
+        timestamp: env.ledger().timestamp(),
+    };
+
+    env.events().publish((MODERATION_EVENT,), payload);
+}
+
 /// ===========================================
 /// TESTS ( BOUNDARY TESTS )
 /// ===========================================
@@ -1,10 +1,11 @@
-use soroban_sdk::{contracttype, Env, Vec};
+use soroban_sdk::{contracterror, contracttype, Env};
 
 // #403: explicit bounds to keep storage/event payloads predictable.
 pub const MAX_CONFESSION_CONTENT_LEN: u32 = 2048;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
 pub enum ConfessionError {
     /// Submitted content string has zero length.
     ContentEmpty = 1,
@@ -13,15 +14,7 @@ pub enum ConfessionError {
 }
  
 #[contracttype]
-#[derive(Clone)]
-pub struct Confession {
-    pub id: u64,
-    pub created_seq: u64,
-    pub content: soroban_sdk::String,
-}
-
-#[contracttype]
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Confession {
     pub id: u64,
     pub created_seq: u64,
@@ -37,7 +30,7 @@ pub enum ConfessionKey {
 }
 
 pub fn create(env: &Env, content: soroban_sdk::String) -> Result<u64, ConfessionError> {
-    if content.len() == 0 {
+    if content.is_empty() {
         return Err(ConfessionError::ContentEmpty);
     }
     if content.len() > MAX_CONFESSION_CONTENT_LEN {
@@ -60,11 +53,11 @@ pub fn create(env: &Env, content: soroban_sdk::String) -> Result<u64, Confession
     };
 
     env.storage()
-        .instance()
+        .persistent()
         .set(&ConfessionKey::Registry(id), &confession);
 
     env.storage()
-        .instance()
+        .persistent()
         .set(&ConfessionKey::Index((created_seq, id)), &id);
 
     env.storage()
@@ -1,7 +1,7 @@
-use soroban_sdk::{contracttype, Env, Vec};
+use soroban_sdk::{contracttype, Env};
 
 #[contracttype]
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Report {
     pub id: u64,
     pub created_seq: u64,
@@ -24,7 +24,7 @@ pub fn create(env: &Env, confession_id: u64) -> u64 {
         .unwrap_or(0);
 
     id += 1;
-    let created_seq = env.ledger().sequence();
+    let created_seq = env.ledger().sequence() as u64;
 
     let report = Report {
         id,
@@ -33,11 +33,11 @@ pub fn create(env: &Env, confession_id: u64) -> u64 {
     };
 
     env.storage()
-        .instance()
+        .persistent()
         .set(&ReportKey::Registry(id), &report);
 
     env.storage()
-        .instance()
+        .persistent()
         .set(&ReportKey::Index((created_seq, id)), &id);
 
     env.storage()
@@ -1,108 +1,119 @@
-use soroban_sdk::{contractimpl, contracttype, symbol, Env, String as SorobanString, Symbol, Storage};
-use crate::{
-    report_key, ERR_COOLDOWN_ACTIVE, ERR_DUPLICATE_REPORT, ERR_REASON_EMPTY, ERR_REASON_TOO_LONG,
-};
+use soroban_sdk::{contracterror, contracttype, symbol_short, Address, Env, String as SorobanString};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ReportError {
+    /// Submitted reason string has zero length.
+    ReasonEmpty = 1,
+    /// Submitted reason exceeds MAX_REPORT_REASON_LEN characters.
+    ReasonTooLong = 2,
+    /// Actor already reported this confession within the cooldown window.
+    CooldownActive = 3,
+    /// Actor already reported this confession.
+    ReportExists = 4,
+}
 
+/// Storage key for the actor-confession report mapping.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-enum ReportNonceKey {
-    Stream(Symbol),
+pub enum ReportKey {
+    /// Timestamp of `actor`'s report against `confession_id`.
+    Submitted(Address, u64),
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ReportSubmittedEvent {
-    pub confession_id: Symbol,
-    pub actor: Symbol,
+    pub confession_id: u64,
+    pub actor: Address,
     pub reason: SorobanString,
     pub nonce: u64,
     pub timestamp: u64,
 }
 
+/// Report markers are extended to ~30 days once they fall below ~7 days
+/// (at ~5s per ledger), so a lapsed marker cannot re-open a duplicate report.
+const REPORT_TTL_THRESHOLD: u32 = 17_280 * 7;
+const REPORT_TTL_EXTEND_TO: u32 = 17_280 * 30;
+
+fn extend_marker(env: &Env, key: &ReportKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, REPORT_TTL_THRESHOLD, REPORT_TTL_EXTEND_TO);
+}
+
 pub struct ReportContract;
 
-#[contractimpl]
 impl ReportContract {
     // Cooldown window in seconds
     pub const COOLDOWN: u64 = 3600; // 1 hour
     // #403: hard bound for report reason payload.
     pub const MAX_REPORT_REASON_LEN: u32 = 128;
 
-    fn latest_nonce_internal(env: &Env, confession_id: &Symbol) -> u64 {
-        env.storage()
-            .instance()
-            .get(&ReportNonceKey::Stream(confession_id.clone()))
-            .unwrap_or(0_u64)
-    }
-
-    fn bump_nonce(env: &Env, confession_id: &Symbol) -> u64 {
-        let next = Self::latest_nonce_internal(env, confession_id)
-            .checked_add(1)
-            .expect("report event nonce overflow");
-
-        env.storage()
-            .instance()
-            .set(&ReportNonceKey::Stream(confession_id.clone()), &next);
-
-        next
-    }
-
     // Backward-compatible entrypoint: uses a default bounded reason.
-    pub fn submit_report(env: Env, actor: Symbol, confession_id: Symbol) -> Result<(), Symbol> {
-        let default_reason = SorobanString::from_str(&env, "generic");
-        Self::submit_report_with_reason(
-            env,
-            actor,
-            confession_id,
-            default_reason,
-        )
+    pub fn submit_report(env: &Env, actor: &Address, confession_id: u64) -> Result<(), ReportError> {
+        let default_reason = SorobanString::from_str(env, "generic");
+        Self::submit_report_with_reason(env, actor, confession_id, default_reason)
     }
 
     // Submit a report with explicit reason (bounded for gas/indexer safety).
     pub fn submit_report_with_reason(
-        env: Env,
-        actor: Symbol,
-        confession_id: Symbol,
+        env: &Env,
+        actor: &Address,
+        confession_id: u64,
         reason: SorobanString,
-    ) -> Result<(), Symbol> {
-        if reason.len() == 0 {
-            return Err(symbol!(ERR_REASON_EMPTY));
+    ) -> Result<(), ReportError> {
+        if reason.is_empty() {
+            return Err(ReportError::ReasonEmpty);
         }
         if reason.len() > Self::MAX_REPORT_REASON_LEN {
-            return Err(symbol!(ERR_REASON_TOO_LONG));
+            return Err(ReportError::ReasonTooLong);
         }
 
-        let storage = env.storage();
-        let key = report_key(&actor, &confession_id);
+        let storage = env.storage().persistent();
+        let key = ReportKey::Submitted(actor.clone(), confession_id);
 
         if let Some(last_timestamp) = storage.get::<_, u64>(&key) {
             let now = env.ledger().timestamp();
             if now - last_timestamp < Self::COOLDOWN {
-                return Err(symbol!(ERR_COOLDOWN_ACTIVE));
+                return Err(ReportError::CooldownActive);
             } else {
-                return Err(symbol!(ERR_DUPLICATE_REPORT));
+                return Err(ReportError::ReportExists);
             }
         }
 
         // Save current timestamp for this actor-confession
         storage.set(&key, &env.ledger().timestamp());
+        extend_marker(env, &key);
 
         // Emit deterministic report lifecycle event with monotonic nonce.
-        let nonce = Self::bump_nonce(&env, &confession_id);
+        // The nonce lives in the shared `events` streams so checkpoints see it.
+        let nonce = crate::events::next_report_nonce(env, confession_id);
         let payload = ReportSubmittedEvent {
-            confession_id: confession_id.clone(),
+            confession_id,
             actor: actor.clone(),
             reason,
             nonce,
             timestamp: env.ledger().timestamp(),
         };
-        env.events().publish((symbol!("report"),), payload);
+        env.events().publish((symbol_short!("report"),), payload);
 
         Ok(())
     }
 
     // Read helper for reconciliation/indexers.
-    pub fn latest_report_nonce(env: Env, confession_id: Symbol) -> u64 {
-        Self::latest_nonce_internal(&env, &confession_id)
+    pub fn latest_report_nonce(env: &Env, confession_id: u64) -> u64 {
+        crate::events::latest_report_nonce(env, confession_id)
+    }
+
+    /// Returns `true` if `actor` has already reported `confession_id`.
+    pub fn has_reported(env: &Env, actor: &Address, confession_id: u64) -> bool {
+        let key = ReportKey::Submitted(actor.clone(), confession_id);
+        let reported = env.storage().persistent().has(&key);
+        if reported {
+            extend_marker(env, &key);
+        }
+        reported
     }
 }
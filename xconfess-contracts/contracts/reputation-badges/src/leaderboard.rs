@@ -0,0 +1,110 @@
+//! Bounded on-chain reputation leaderboard.
+//!
+//! The top `LEADERBOARD_SIZE` users are kept in a single entry, ordered by
+//! reputation descending. Every reputation change re-inserts the user, so an
+//! update touches at most `LEADERBOARD_SIZE` entries however many users exist.
+//! Equal scores keep the order in which they were reached: a user moving to a
+//! tied score ranks below those already holding it.
+//!
+//! Scores are snapshots taken at the user's last change or refresh. Time
+//! decay is not reflected until then, so anyone may refresh a user to
+//! re-score and re-insert them: a decayed entry drops to its place, and a
+//! user who now outranks the last entry takes its slot. A config change
+//! re-scores every entry on the board; users off the board only enter on
+//! their next change or refresh.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{reputation, StorageKey};
+
+/// Maximum number of users kept on the leaderboard.
+pub const LEADERBOARD_SIZE: u32 = 100;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub user: Address,
+    pub reputation: i128,
+}
+
+fn load(env: &Env) -> Vec<LeaderboardEntry> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::Leaderboard)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Move `user` to its position for `reputation`, dropping whoever falls
+/// off the end.
+pub fn update(env: &Env, user: &Address, reputation: i128) {
+    let mut entries = load(env);
+
+    if let Some(index) = entries.iter().position(|entry| entry.user == *user) {
+        entries.remove(index as u32);
+    }
+
+    let position = entries
+        .iter()
+        .position(|entry| entry.reputation < reputation)
+        .unwrap_or(entries.len() as usize) as u32;
+    if position >= LEADERBOARD_SIZE {
+        env.storage()
+            .persistent()
+            .set(&StorageKey::Leaderboard, &entries);
+        return;
+    }
+
+    entries.insert(
+        position,
+        LeaderboardEntry {
+            user: user.clone(),
+            reputation,
+        },
+    );
+    if entries.len() > LEADERBOARD_SIZE {
+        entries.pop_back();
+    }
+
+    env.storage()
+        .persistent()
+        .set(&StorageKey::Leaderboard, &entries);
+}
+
+/// Re-score every entry with decay and the current config applied, and
+/// re-order the board. Bounded by `LEADERBOARD_SIZE` entries.
+pub fn rescore(env: &Env) {
+    let mut entries: Vec<LeaderboardEntry> = Vec::new(env);
+    for entry in load(env).iter() {
+        let reputation = reputation::total(env, &entry.user);
+        let position = entries
+            .iter()
+            .position(|other| other.reputation < reputation)
+            .unwrap_or(entries.len() as usize) as u32;
+        entries.insert(
+            position,
+            LeaderboardEntry {
+                user: entry.user,
+                reputation,
+            },
+        );
+    }
+
+    env.storage()
+        .persistent()
+        .set(&StorageKey::Leaderboard, &entries);
+}
+
+pub fn page(env: &Env, offset: u32, limit: u32) -> Vec<LeaderboardEntry> {
+    let entries = load(env);
+    let start = offset.min(entries.len());
+    let end = start.saturating_add(limit).min(entries.len());
+    entries.slice(start..end)
+}
+
+/// 1-based rank, or `None` if the user is not on the leaderboard.
+pub fn rank(env: &Env, user: &Address) -> Option<u32> {
+    load(env)
+        .iter()
+        .position(|entry| entry.user == *user)
+        .map(|index| index as u32 + 1)
+}
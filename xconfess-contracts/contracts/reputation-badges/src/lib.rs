@@ -1,6 +1,15 @@
 #![no_std]
 
-use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, xdr::ToXdr, Address,
+    BytesN, Env, String, Symbol, Vec,
+};
+
+mod leaderboard;
+mod reputation;
+
+pub use leaderboard::{LeaderboardEntry, LEADERBOARD_SIZE};
+pub use reputation::{ReputationBreakdown, ReputationConfig, ReputationWeights};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -12,6 +21,16 @@ pub enum Error {
     NotAuthorized = 4,
     NotInitialized = 5,
     BadgeTypeMetadataNotFound = 6,
+    InvalidActivityCount = 7,
+    NotEligible = 8,
+    VoucherExpired = 9,
+    VoucherAlreadyUsed = 10,
+    TransferNotAllowed = 11,
+    UnknownBadgeType = 12,
+    BadgeTypeRetired = 13,
+    MaxSupplyReached = 14,
+    InvalidLevel = 15,
+    InvalidReputationConfig = 16,
 }
 
 #[contracttype]
@@ -22,6 +41,93 @@ pub enum BadgeType {
     GenerousSoul,      // Tipped 10+ confessions
     CommunityHero,     // 50+ confessions posted
     TopReactor,        // 500+ reactions given
+    /// Admin-created catalogue entry, IDs from `FIRST_CUSTOM_BADGE_TYPE_ID`
+    Custom(u32),
+}
+
+/// Type IDs below this are reserved; the legacy variants use 0-4.
+pub const FIRST_CUSTOM_BADGE_TYPE_ID: u32 = 100;
+/// Highest badge level (1 = bronze, 2 = silver, 3 = gold).
+pub const MAX_BADGE_LEVEL: u32 = 3;
+/// Version 2 added the badge level to `BadgeEvent`.
+const BADGE_EVENT_VERSION: u32 = 2;
+
+impl BadgeType {
+    /// Numeric type ID, as carried in `BadgeEvent.badge_type`
+    pub fn id(&self) -> u32 {
+        match self {
+            BadgeType::ConfessionStarter => 0,
+            BadgeType::PopularVoice => 1,
+            BadgeType::GenerousSoul => 2,
+            BadgeType::CommunityHero => 3,
+            BadgeType::TopReactor => 4,
+            BadgeType::Custom(id) => *id,
+        }
+    }
+
+    /// Badge type for a numeric ID; reserved IDs map to the legacy variants
+    pub fn from_id(id: u32) -> BadgeType {
+        match id {
+            0 => BadgeType::ConfessionStarter,
+            1 => BadgeType::PopularVoice,
+            2 => BadgeType::GenerousSoul,
+            3 => BadgeType::CommunityHero,
+            4 => BadgeType::TopReactor,
+            id => BadgeType::Custom(id),
+        }
+    }
+}
+
+/// Legacy badge types, in reserved-ID order.
+const LEGACY_BADGE_TYPES: [BadgeType; 5] = [
+    BadgeType::ConfessionStarter,
+    BadgeType::PopularVoice,
+    BadgeType::GenerousSoul,
+    BadgeType::CommunityHero,
+    BadgeType::TopReactor,
+];
+
+/// On-chain activity that authorized source contracts attest to.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ActivityKind {
+    ConfessionPosted,
+    ReactionReceived,
+    ReactionGiven,
+    TipSent,
+    TipReceived,
+    ReportUpheld,
+}
+
+/// The activity counter that earns `badge_type` and its threshold for each
+/// level, lowest first. Catalogue types have no on-chain criterion and are
+/// only awarded or vouched for.
+fn badge_criterion(badge_type: &BadgeType) -> Option<(ActivityKind, &'static [u64])> {
+    match badge_type {
+        BadgeType::ConfessionStarter => Some((ActivityKind::ConfessionPosted, &[1])),
+        BadgeType::PopularVoice => Some((ActivityKind::ReactionReceived, &[100, 1_000, 10_000])),
+        BadgeType::GenerousSoul => Some((ActivityKind::TipSent, &[10, 100, 1_000])),
+        BadgeType::CommunityHero => Some((ActivityKind::ConfessionPosted, &[50, 500, 5_000])),
+        BadgeType::TopReactor => Some((ActivityKind::ReactionGiven, &[500, 5_000, 50_000])),
+        BadgeType::Custom(_) => None,
+    }
+}
+
+/// Number of level thresholds `total` meets; 0 if the badge is not earned.
+fn earned_level(thresholds: &[u64], total: u64) -> u32 {
+    thresholds.iter().filter(|threshold| total >= **threshold).count() as u32
+}
+
+/// Whether badges of a type may change hands
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TransferPolicy {
+    /// The owner may transfer freely (default for types without metadata)
+    Transferable,
+    /// Bound to the owner; can only be revoked
+    Soulbound,
+    /// Each transfer must first be approved by the admin
+    AdminApproved,
 }
 
 #[contracttype]
@@ -30,6 +136,12 @@ pub struct BadgeTypeMetadata {
     pub name: String,
     pub description: String,
     pub criteria: String,
+    pub icon_uri: String,
+    /// Maximum badges ever minted of this type; 0 means unlimited
+    pub max_supply: u32,
+    pub transfer_policy: TransferPolicy,
+    /// Retired types can no longer be minted; existing badges are kept
+    pub retired: bool,
 }
 
 #[contracttype]
@@ -39,6 +151,16 @@ pub struct Badge {
     pub badge_type: BadgeType,
     pub minted_at: u64,
     pub owner: Address,
+    /// Starts at the minted level and is raised in place by upgrades
+    pub level: u32,
+}
+
+/// A badge together with its type's current transfer policy
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnedBadge {
+    pub badge: Badge,
+    pub transfer_policy: TransferPolicy,
 }
 
 /// Storage keys
@@ -59,6 +181,43 @@ pub enum StorageKey {
     BadgeTypeMetadata(BadgeType),
     /// User reputation: StorageKey::UserReputation(user) -> i128
     UserReputation(Address),
+    /// Authorized activity source: StorageKey::Attestor(source) -> bool
+    Attestor(Address),
+    /// Activity counter: StorageKey::ActivityCount(user, kind) -> u64
+    ActivityCount(Address, ActivityKind),
+    /// Badge type already earned from activity: StorageKey::Earned(user, badge_type) -> bool
+    Earned(Address, BadgeType),
+    /// ed25519 public key that signs eligibility vouchers
+    VoucherSigner,
+    /// Consumed voucher nonce: StorageKey::VoucherUsed(nonce) -> bool
+    VoucherUsed(u64),
+    /// Admin-approved transfer recipient: StorageKey::TransferApproval(badge_id) -> Address
+    TransferApproval(u64),
+    /// Badges ever minted of a type: StorageKey::TypeSupply(badge_type) -> u32
+    TypeSupply(BadgeType),
+    /// Next catalogue type ID to assign
+    NextBadgeTypeId,
+    /// Reputation weights, decay and bounds
+    ReputationConfig,
+    /// Activity-driven reputation: StorageKey::ActivityScore(user) -> ActivityScore
+    ActivityScore(Address),
+    /// Top users by reputation: StorageKey::Leaderboard -> Vec<LeaderboardEntry>
+    Leaderboard,
+    /// Replaced reputation decay rates, oldest first: Vec<DecayEpoch>
+    DecayHistory,
+}
+
+/// Admin-signed proof that `recipient` may mint `badge_type`.
+///
+/// The signer signs the XDR encoding of `(contract_address, voucher)`, which
+/// binds the voucher to this contract. Each nonce can be redeemed once.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeVoucher {
+    pub recipient: Address,
+    pub badge_type: BadgeType,
+    pub nonce: u64,
+    pub expires_at: u64,
 }
 
 #[contracttype]
@@ -66,6 +225,7 @@ pub enum StorageKey {
 pub enum BadgeAction {
     Grant,
     Revoke,
+    Upgrade,
 }
 
 #[contracttype]
@@ -76,6 +236,7 @@ pub struct BadgeEvent {
     pub badge_type: u32,
     pub owner: Address,
     pub action: BadgeAction,
+    pub level: u32,
     pub timestamp: u64,
 }
 
@@ -98,6 +259,113 @@ pub struct ReputationAdjustedData {
     pub timestamp: u64,
 }
 
+/// Event data for an activity attestation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityRecordedData {
+    pub source: Address,
+    pub user: Address,
+    pub kind: ActivityKind,
+    pub count: u32,
+    pub total: u64,
+    /// The user's reputation after this activity
+    pub reputation: i128,
+    pub timestamp: u64,
+}
+
+/// Emitted by `add_attestor`: `("attestor_added", admin)` → `source`
+#[contractevent(topics = ["attestor_added"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestorAdded {
+    #[topic]
+    pub admin: Address,
+    pub source: Address,
+}
+
+/// Emitted by `remove_attestor`: `("attestor_removed", admin)` → `source`
+#[contractevent(topics = ["attestor_removed"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestorRemoved {
+    #[topic]
+    pub admin: Address,
+    pub source: Address,
+}
+
+/// Emitted by `record_activity`: `("activity_recorded", user)` → `ActivityRecordedData`
+#[contractevent(topics = ["activity_recorded"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityRecorded {
+    #[topic]
+    pub user: Address,
+    pub data: ActivityRecordedData,
+}
+
+/// Emitted by `set_voucher_signer`: `("voucher_signer_set", admin)` → `public_key`
+#[contractevent(topics = ["voucher_signer_set"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoucherSignerSet {
+    #[topic]
+    pub admin: Address,
+    pub public_key: BytesN<32>,
+}
+
+/// Emitted by `set_transfer_policy`: `("transfer_policy_set", admin)` →
+/// `(badge_type, transfer_policy)`
+#[contractevent(topics = ["transfer_policy_set"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferPolicySet {
+    #[topic]
+    pub admin: Address,
+    pub badge_type: BadgeType,
+    pub transfer_policy: TransferPolicy,
+}
+
+/// Emitted by `approve_transfer`: `("transfer_approved", badge_id)` → `to`
+#[contractevent(topics = ["transfer_approved"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferApproved {
+    #[topic]
+    pub badge_id: u64,
+    pub to: Address,
+}
+
+/// Emitted by `create_badge` and `create_badge_type`:
+/// `("badge_type_created", admin)` → `badge_type`
+#[contractevent(topics = ["badge_type_created"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeTypeCreated {
+    #[topic]
+    pub admin: Address,
+    pub badge_type: BadgeType,
+}
+
+/// Emitted by `retire_badge_type`: `("badge_type_retired", admin)` → `badge_type`
+#[contractevent(topics = ["badge_type_retired"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeTypeRetired {
+    #[topic]
+    pub admin: Address,
+    pub badge_type: BadgeType,
+}
+
+/// Emitted when a badge is upgraded: `("badge_upgraded", owner)` → `BadgeEvent`
+#[contractevent(topics = ["badge_upgraded"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeUpgraded {
+    #[topic]
+    pub owner: Address,
+    pub event: BadgeEvent,
+}
+
+/// Emitted by `set_reputation_config`: `("reputation_config_updated", admin)` → `config`
+#[contractevent(topics = ["reputation_config_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationConfigUpdated {
+    #[topic]
+    pub admin: Address,
+    pub config: ReputationConfig,
+}
+
 #[contract]
 pub struct ReputationBadges;
 
@@ -114,6 +382,183 @@ fn is_authorized(env: &Env, caller: &Address) -> Result<bool, Error> {
     Ok(admin == *caller)
 }
 
+fn badge_type_metadata(env: &Env, badge_type: &BadgeType) -> Option<BadgeTypeMetadata> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::BadgeTypeMetadata(badge_type.clone()))
+}
+
+/// Metadata for a known badge type. Legacy types are always known, even
+/// without metadata; catalogue types must have been created.
+fn require_known_type(
+    env: &Env,
+    badge_type: &BadgeType,
+) -> Result<Option<BadgeTypeMetadata>, Error> {
+    let metadata = badge_type_metadata(env, badge_type);
+    match badge_type {
+        BadgeType::Custom(id) if *id < FIRST_CUSTOM_BADGE_TYPE_ID || metadata.is_none() => {
+            Err(Error::UnknownBadgeType)
+        }
+        _ => Ok(metadata),
+    }
+}
+
+fn type_supply(env: &Env, badge_type: &BadgeType) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::TypeSupply(badge_type.clone()))
+        .unwrap_or(0u32)
+}
+
+/// The badge of `badge_type` currently held by `owner`, if any.
+fn find_badge(env: &Env, owner: &Address, badge_type: &BadgeType) -> Option<Badge> {
+    let ownership_key = StorageKey::TypeOwnership(owner.clone(), badge_type.clone());
+    if !env.storage().persistent().has(&ownership_key) {
+        return None;
+    }
+
+    let badge_ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&StorageKey::UserBadges(owner.clone()))
+        .unwrap_or(Vec::new(env));
+    for badge_id in badge_ids.iter() {
+        let badge: Option<Badge> = env.storage().persistent().get(&StorageKey::Badge(badge_id));
+        if let Some(badge) = badge {
+            if badge.badge_type == *badge_type {
+                return Some(badge);
+            }
+        }
+    }
+    None
+}
+
+/// Raise `badge` to `new_level` and emit an upgrade `BadgeEvent`.
+fn upgrade_internal(env: &Env, mut badge: Badge, new_level: u32) -> Result<(), Error> {
+    if new_level <= badge.level || new_level > MAX_BADGE_LEVEL {
+        return Err(Error::InvalidLevel);
+    }
+    badge.level = new_level;
+    env.storage()
+        .persistent()
+        .set(&StorageKey::Badge(badge.id), &badge);
+
+    let event_payload = BadgeEvent {
+        event_version: BADGE_EVENT_VERSION,
+        badge_id: badge.id,
+        badge_type: badge.badge_type.id(),
+        owner: badge.owner.clone(),
+        action: BadgeAction::Upgrade,
+        level: new_level,
+        timestamp: env.ledger().timestamp(),
+    };
+    BadgeUpgraded {
+        owner: badge.owner,
+        event: event_payload,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+fn transfer_policy(env: &Env, badge_type: &BadgeType) -> TransferPolicy {
+    badge_type_metadata(env, badge_type)
+        .map(|metadata| metadata.transfer_policy)
+        .unwrap_or(TransferPolicy::Transferable)
+}
+
+fn activity_count(env: &Env, user: &Address, kind: ActivityKind) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::ActivityCount(user.clone(), kind))
+        .unwrap_or(0u64)
+}
+
+/// Mint `badge_type` at `level` to `recipient` and emit a grant `BadgeEvent`
+/// under `topic`. Authorization is the caller's responsibility.
+fn mint_internal(
+    env: &Env,
+    recipient: &Address,
+    badge_type: &BadgeType,
+    level: u32,
+    topic: &str,
+) -> Result<u64, Error> {
+    // Check the type is mintable and has supply left
+    let supply = type_supply(env, badge_type);
+    if let Some(metadata) = require_known_type(env, badge_type)? {
+        if metadata.retired {
+            return Err(Error::BadgeTypeRetired);
+        }
+        if metadata.max_supply > 0 && supply >= metadata.max_supply {
+            return Err(Error::MaxSupplyReached);
+        }
+    }
+
+    // Check if recipient already has this badge type
+    let ownership_key = StorageKey::TypeOwnership(recipient.clone(), badge_type.clone());
+    if env.storage().persistent().has(&ownership_key) {
+        return Err(Error::BadgeAlreadyOwned);
+    }
+    env.storage()
+        .persistent()
+        .set(&StorageKey::TypeSupply(badge_type.clone()), &(supply + 1));
+
+    // Get and increment badge count
+    let badge_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&StorageKey::BadgeCount)
+        .unwrap_or(0u64);
+    let badge_id = badge_count + 1;
+    env.storage()
+        .persistent()
+        .set(&StorageKey::BadgeCount, &badge_id);
+
+    // Create badge
+    let minted_at = env.ledger().timestamp();
+    let badge = Badge {
+        id: badge_id,
+        badge_type: badge_type.clone(),
+        minted_at,
+        owner: recipient.clone(),
+        level,
+    };
+
+    // Store badge
+    env.storage()
+        .persistent()
+        .set(&StorageKey::Badge(badge_id), &badge);
+
+    // Mark type ownership
+    env.storage().persistent().set(&ownership_key, &true);
+
+    // Update user's badge list
+    let user_badges_key = StorageKey::UserBadges(recipient.clone());
+    let mut user_badges: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&user_badges_key)
+        .unwrap_or(Vec::new(env));
+    user_badges.push_back(badge_id);
+    env.storage()
+        .persistent()
+        .set(&user_badges_key, &user_badges);
+
+    let event_payload = BadgeEvent {
+        event_version: BADGE_EVENT_VERSION,
+        badge_id,
+        badge_type: badge_type.id(),
+        owner: recipient.clone(),
+        action: BadgeAction::Grant,
+        level,
+        timestamp: minted_at,
+    };
+    env.events()
+        .publish((Symbol::new(env, topic), recipient.clone()), event_payload);
+
+    Ok(badge_id)
+}
+
 #[contractimpl]
 impl ReputationBadges {
     /// Initialize the contract with an admin
@@ -153,6 +598,7 @@ impl ReputationBadges {
     }
 
     /// Create or update metadata for a badge type (admin only)
+    /// New types start `Transferable`; see `set_transfer_policy`
     pub fn create_badge(
         env: Env,
         badge_type: BadgeType,
@@ -163,89 +609,146 @@ impl ReputationBadges {
         let admin = get_admin(&env)?;
         admin.require_auth();
 
+        let existing = require_known_type(&env, &badge_type)?;
         let metadata = BadgeTypeMetadata {
             name,
             description,
             criteria,
+            icon_uri: existing
+                .as_ref()
+                .map(|m| m.icon_uri.clone())
+                .unwrap_or(String::from_str(&env, "")),
+            max_supply: existing.as_ref().map(|m| m.max_supply).unwrap_or(0),
+            transfer_policy: existing
+                .as_ref()
+                .map(|m| m.transfer_policy)
+                .unwrap_or(TransferPolicy::Transferable),
+            retired: existing.map(|m| m.retired).unwrap_or(false),
         };
 
         env.storage()
             .persistent()
             .set(&StorageKey::BadgeTypeMetadata(badge_type.clone()), &metadata);
 
-        let event_topic = Symbol::new(&env, "badge_type_created");
-        env.events()
-            .publish((event_topic, admin.clone()), badge_type);
+        BadgeTypeCreated { admin, badge_type }.publish(&env);
 
         Ok(())
     }
 
-    /// Award a badge to a user (admin only)
-    /// Returns the badge ID
-    pub fn award_badge(env: Env, recipient: Address, badge_type: BadgeType) -> Result<u64, Error> {
+    /// Add a badge type to the catalogue (admin only)
+    /// Returns the new type's ID, usable as `BadgeType::Custom(id)`
+    pub fn create_badge_type(
+        env: Env,
+        name: String,
+        description: String,
+        criteria: String,
+        icon_uri: String,
+        max_supply: u32,
+        transfer_policy: TransferPolicy,
+    ) -> Result<u32, Error> {
         let admin = get_admin(&env)?;
         admin.require_auth();
 
-        // Check if recipient already has this badge type
-        let ownership_key = StorageKey::TypeOwnership(recipient.clone(), badge_type.clone());
-        if env.storage().persistent().has(&ownership_key) {
-            return Err(Error::BadgeAlreadyOwned);
-        }
-
-        // Get and increment badge count
-        let badge_count = Self::get_badge_count_internal(&env);
-        let badge_id = badge_count + 1;
+        let type_id: u32 = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::NextBadgeTypeId)
+            .unwrap_or(FIRST_CUSTOM_BADGE_TYPE_ID);
         env.storage()
             .persistent()
-            .set(&StorageKey::BadgeCount, &badge_id);
-
-        // Create badge
-        let minted_at = env.ledger().timestamp();
-        let badge = Badge {
-            id: badge_id,
-            badge_type: badge_type.clone(),
-            minted_at,
-            owner: recipient.clone(),
+            .set(&StorageKey::NextBadgeTypeId, &(type_id + 1));
+
+        let badge_type = BadgeType::Custom(type_id);
+        let metadata = BadgeTypeMetadata {
+            name,
+            description,
+            criteria,
+            icon_uri,
+            max_supply,
+            transfer_policy,
+            retired: false,
         };
+        env.storage()
+            .persistent()
+            .set(&StorageKey::BadgeTypeMetadata(badge_type.clone()), &metadata);
+
+        BadgeTypeCreated { admin, badge_type }.publish(&env);
+
+        Ok(type_id)
+    }
+
+    /// Set the transfer policy of a badge type with metadata (admin only)
+    pub fn set_transfer_policy(
+        env: Env,
+        badge_type: BadgeType,
+        transfer_policy: TransferPolicy,
+    ) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
 
-        // Store badge
+        let mut metadata = require_known_type(&env, &badge_type)?
+            .ok_or(Error::BadgeTypeMetadataNotFound)?;
+        metadata.transfer_policy = transfer_policy;
         env.storage()
             .persistent()
-            .set(&StorageKey::Badge(badge_id), &badge);
+            .set(&StorageKey::BadgeTypeMetadata(badge_type.clone()), &metadata);
 
-        // Mark type ownership
-        env.storage().persistent().set(&ownership_key, &true);
+        TransferPolicySet {
+            admin,
+            badge_type,
+            transfer_policy,
+        }
+        .publish(&env);
 
-        // Update user's badge list
-        let user_badges_key = StorageKey::UserBadges(recipient.clone());
-        let mut user_badges: Vec<u64> = env
-            .storage()
+        Ok(())
+    }
+
+    /// Retire a badge type so no more badges of it can be minted (admin only)
+    pub fn retire_badge_type(env: Env, badge_type: BadgeType) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        let mut metadata = require_known_type(&env, &badge_type)?
+            .ok_or(Error::BadgeTypeMetadataNotFound)?;
+        metadata.retired = true;
+        env.storage()
             .persistent()
-            .get(&user_badges_key)
-            .unwrap_or(Vec::new(&env));
-        user_badges.push_back(badge_id);
+            .set(&StorageKey::BadgeTypeMetadata(badge_type.clone()), &metadata);
+
+        BadgeTypeRetired { admin, badge_type }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get catalogue metadata by numeric type ID
+    pub fn get_badge_type(env: Env, type_id: u32) -> Option<BadgeTypeMetadata> {
+        badge_type_metadata(&env, &BadgeType::from_id(type_id))
+    }
+
+    /// Get the number of badges ever minted of a type
+    pub fn get_badge_type_supply(env: Env, badge_type: BadgeType) -> u32 {
+        type_supply(&env, &badge_type)
+    }
+
+    /// Get the ID the next catalogue type will receive
+    pub fn get_next_badge_type_id(env: Env) -> u32 {
         env.storage()
             .persistent()
-            .set(&user_badges_key, &user_badges);
+            .get(&StorageKey::NextBadgeTypeId)
+            .unwrap_or(FIRST_CUSTOM_BADGE_TYPE_ID)
+    }
 
-        // Emit BadgeAwarded event
-        let event_payload = BadgeEvent {
-            event_version: 1,
-            badge_id,
-            badge_type: badge_type.clone() as u32,
-            owner: recipient.clone(),
-            action: BadgeAction::Grant,
-            timestamp: minted_at,
-        };
-        env.events().publish(
-            (Symbol::new(&env, "badge_awarded"), recipient.clone()),
-            event_payload,
-        );
+    /// Award a badge to a user (admin only)
+    /// Returns the badge ID
+    pub fn award_badge(env: Env, recipient: Address, badge_type: BadgeType) -> Result<u64, Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
 
-        Ok(badge_id)
+        mint_internal(&env, &recipient, &badge_type, 1, "badge_awarded")
     }
 
     /// Adjust user reputation (admin only)
+    /// Manual adjustments are not decayed. Returns the new reputation total
     pub fn adjust_reputation(
         env: Env,
         user: Address,
@@ -255,12 +758,10 @@ impl ReputationBadges {
         let admin = get_admin(&env)?;
         admin.require_auth();
 
-        let current_reputation = Self::get_user_reputation_internal(&env, &user);
-        let new_reputation = current_reputation + amount;
-
-        env.storage()
-            .persistent()
-            .set(&StorageKey::UserReputation(user.clone()), &new_reputation);
+        let manual = reputation::manual(&env, &user).saturating_add(amount);
+        reputation::set_manual(&env, &user, manual);
+        let new_reputation = reputation::total(&env, &user);
+        leaderboard::update(&env, &user, new_reputation);
 
         let event_topic = Symbol::new(&env, "reputation_adjusted");
         env.events().publish(
@@ -276,77 +777,273 @@ impl ReputationBadges {
         Ok(new_reputation)
     }
 
-    /// Get user reputation
+    /// Get user reputation, with decay applied up to the current ledger time
     pub fn get_user_reputation(env: Env, user: Address) -> i128 {
-        Self::get_user_reputation_internal(&env, &user)
+        reputation::total(&env, &user)
+    }
+
+    /// Get the contribution of each category to a user's reputation
+    pub fn get_reputation_breakdown(env: Env, user: Address) -> ReputationBreakdown {
+        reputation::breakdown(&env, &user)
+    }
+
+    /// Set reputation weights, decay and floor/ceiling (admin only)
+    /// A new decay rate applies from now; it does not rescale earlier decay.
+    /// Leaderboard entries are re-scored under the new config; users off the
+    /// board enter on their next change or `refresh_reputation`
+    pub fn set_reputation_config(env: Env, config: ReputationConfig) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        config.validate()?;
+        reputation::set_config(&env, &config);
+        leaderboard::rescore(&env);
+
+        ReputationConfigUpdated { admin, config }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the reputation scoring configuration
+    pub fn get_reputation_config(env: Env) -> ReputationConfig {
+        reputation::config(&env)
+    }
+
+    /// Re-score a user with decay applied up to now and move them to their
+    /// leaderboard position (anyone may call)
+    /// Users without any reputation record are left off the board. Returns
+    /// the user's current reputation
+    pub fn refresh_reputation(env: Env, user: Address) -> i128 {
+        let reputation = reputation::total(&env, &user);
+        if reputation::has_record(&env, &user) {
+            leaderboard::update(&env, &user, reputation);
+        }
+        reputation
+    }
+
+    /// Get a page of the top users by reputation, highest first
+    /// Scores are snapshots from each user's last change or refresh, so decay
+    /// since then is not reflected until `refresh_reputation` is called
+    pub fn get_leaderboard(env: Env, offset: u32, limit: u32) -> Vec<LeaderboardEntry> {
+        leaderboard::page(&env, offset, limit)
+    }
+
+    /// Get a user's 1-based leaderboard rank, or None if not in the top
+    /// `LEADERBOARD_SIZE`
+    /// Ranks follow the stored snapshots and may be stale under decay; see
+    /// `get_leaderboard`
+    pub fn get_rank(env: Env, user: Address) -> Option<u32> {
+        leaderboard::rank(&env, &user)
     }
 
     /// Mint a new badge for a recipient (self-service)
+    /// The recipient's attested activity counters must meet the badge type's
+    /// criterion, and the type must not already have been earned.
     /// Returns the badge ID if successful
     pub fn mint_badge(env: Env, recipient: Address, badge_type: BadgeType) -> Result<u64, Error> {
         recipient.require_auth();
 
-        // Check if recipient already has this badge type
-        let ownership_key = StorageKey::TypeOwnership(recipient.clone(), badge_type.clone());
-        if env.storage().persistent().has(&ownership_key) {
-            return Err(Error::BadgeAlreadyOwned);
+        let (kind, thresholds) = badge_criterion(&badge_type).ok_or(Error::NotEligible)?;
+        let level = earned_level(thresholds, activity_count(&env, &recipient, kind));
+        if level == 0 {
+            return Err(Error::NotEligible);
+        }
+        let earned_key = StorageKey::Earned(recipient.clone(), badge_type.clone());
+        if env.storage().persistent().has(&earned_key) {
+            return Err(Error::NotEligible);
+        }
+
+        let badge_id = mint_internal(&env, &recipient, &badge_type, level, "badge_granted")?;
+        env.storage().persistent().set(&earned_key, &true);
+        Ok(badge_id)
+    }
+
+    /// Mint a badge against an admin-signed eligibility voucher
+    /// Panics if `signature` is not a valid ed25519 signature by the voucher signer
+    pub fn mint_badge_with_voucher(
+        env: Env,
+        voucher: BadgeVoucher,
+        signature: BytesN<64>,
+    ) -> Result<u64, Error> {
+        voucher.recipient.require_auth();
+
+        let signer: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::VoucherSigner)
+            .ok_or(Error::NotInitialized)?;
+        if env.ledger().timestamp() >= voucher.expires_at {
+            return Err(Error::VoucherExpired);
+        }
+        let nonce_key = StorageKey::VoucherUsed(voucher.nonce);
+        if env.storage().persistent().has(&nonce_key) {
+            return Err(Error::VoucherAlreadyUsed);
         }
 
-        // Get and increment badge count
-        let badge_count = Self::get_badge_count_internal(&env);
-        let badge_id = badge_count + 1;
+        let message = (env.current_contract_address(), voucher.clone()).to_xdr(&env);
+        env.crypto().ed25519_verify(&signer, &message, &signature);
+
+        env.storage().persistent().set(&nonce_key, &true);
+        mint_internal(&env, &voucher.recipient, &voucher.badge_type, 1, "badge_granted")
+    }
+
+    /// Set the ed25519 public key that signs eligibility vouchers (admin only)
+    pub fn set_voucher_signer(env: Env, public_key: BytesN<32>) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
         env.storage()
             .persistent()
-            .set(&StorageKey::BadgeCount, &badge_id);
-
-        // Create badge
-        let minted_at = env.ledger().timestamp();
-        let badge = Badge {
-            id: badge_id,
-            badge_type: badge_type.clone(),
-            minted_at,
-            owner: recipient.clone(),
-        };
+            .set(&StorageKey::VoucherSigner, &public_key);
+
+        VoucherSignerSet { admin, public_key }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the voucher signing key, if set
+    pub fn get_voucher_signer(env: Env) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&StorageKey::VoucherSigner)
+    }
 
-        // Store badge
+    /// Check if a voucher nonce has been redeemed
+    pub fn is_voucher_used(env: Env, nonce: u64) -> bool {
         env.storage()
             .persistent()
-            .set(&StorageKey::Badge(badge_id), &badge);
+            .has(&StorageKey::VoucherUsed(nonce))
+    }
 
-        // Mark type ownership
-        env.storage().persistent().set(&ownership_key, &true);
+    /// Authorize a source contract (e.g. confession-registry, tipping) to
+    /// attest user activity (admin only)
+    pub fn add_attestor(env: Env, source: Address) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
 
-        // Update user's badge list
-        let user_badges_key = StorageKey::UserBadges(recipient.clone());
-        let mut user_badges: Vec<u64> = env
-            .storage()
+        env.storage()
             .persistent()
-            .get(&user_badges_key)
-            .unwrap_or(Vec::new(&env));
-        user_badges.push_back(badge_id);
+            .set(&StorageKey::Attestor(source.clone()), &true);
+
+        AttestorAdded { admin, source }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Remove an activity source (admin only)
+    pub fn remove_attestor(env: Env, source: Address) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
         env.storage()
             .persistent()
-            .set(&user_badges_key, &user_badges);
+            .remove(&StorageKey::Attestor(source.clone()));
 
-        // Emit BadgeGranted event
-        let event_payload = BadgeEvent {
-            event_version: 1,
-            badge_id,
-            badge_type: badge_type.clone() as u32,
-            owner: recipient.clone(),
-            action: BadgeAction::Grant,
-            timestamp: minted_at,
-        };
-        env.events().publish(
-            (Symbol::new(&env, "badge_granted"), recipient.clone()),
-            event_payload,
-        );
+        AttestorRemoved { admin, source }.publish(&env);
 
-        Ok(badge_id)
+        Ok(())
+    }
+
+    /// Check if an address may attest activity
+    pub fn is_attestor(env: Env, source: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&StorageKey::Attestor(source))
+    }
+
+    /// Record `count` units of `kind` activity for `user`, attested by an
+    /// authorized source contract.
+    ///
+    /// The activity is scored into the user's reputation using the
+    /// configured weights.
+    ///
+    /// Every badge type whose criterion is met by the new counter total is
+    /// minted to `user` at the level reached, once per type, and badges the
+    /// user already holds are upgraded in place when a higher level is
+    /// reached. Returns the IDs of badges minted or upgraded.
+    pub fn record_activity(
+        env: Env,
+        source: Address,
+        user: Address,
+        kind: ActivityKind,
+        count: u32,
+    ) -> Result<Vec<u64>, Error> {
+        source.require_auth();
+
+        if !Self::is_attestor(env.clone(), source.clone()) {
+            return Err(Error::NotAuthorized);
+        }
+        if count == 0 {
+            return Err(Error::InvalidActivityCount);
+        }
+
+        let total = activity_count(&env, &user, kind).saturating_add(count as u64);
+        env.storage()
+            .persistent()
+            .set(&StorageKey::ActivityCount(user.clone(), kind), &total);
+        reputation::record(&env, &user, kind, count);
+        let new_reputation = reputation::total(&env, &user);
+        leaderboard::update(&env, &user, new_reputation);
+
+        ActivityRecorded {
+            user: user.clone(),
+            data: ActivityRecordedData {
+                source,
+                user: user.clone(),
+                kind,
+                count,
+                total,
+                reputation: new_reputation,
+                timestamp: env.ledger().timestamp(),
+            },
+        }
+        .publish(&env);
+
+        // Auto-mint newly earned badges. A type is earned at most once, so
+        // transferring or revoking it does not make it mintable again.
+        let mut minted = Vec::new(&env);
+        for badge_type in LEGACY_BADGE_TYPES.iter() {
+            let Some((criterion_kind, thresholds)) = badge_criterion(badge_type) else {
+                continue;
+            };
+            let level = earned_level(thresholds, total);
+            if criterion_kind != kind || level == 0 {
+                continue;
+            }
+
+            if let Some(badge) = find_badge(&env, &user, badge_type) {
+                if level > badge.level {
+                    let badge_id = badge.id;
+                    upgrade_internal(&env, badge, level)?;
+                    minted.push_back(badge_id);
+                }
+                continue;
+            }
+
+            let earned_key = StorageKey::Earned(user.clone(), badge_type.clone());
+            let ownership_key = StorageKey::TypeOwnership(user.clone(), badge_type.clone());
+            if env.storage().persistent().has(&earned_key)
+                || env.storage().persistent().has(&ownership_key)
+            {
+                continue;
+            }
+
+            // Retired or sold-out types are skipped rather than failing the
+            // attestation.
+            if let Ok(badge_id) = mint_internal(&env, &user, badge_type, level, "badge_earned") {
+                env.storage().persistent().set(&earned_key, &true);
+                minted.push_back(badge_id);
+            }
+        }
+
+        Ok(minted)
     }
 
-    /// Get all badges owned by an address
-    pub fn get_badges(env: Env, owner: Address) -> Vec<Badge> {
+    /// Get a user's attested activity counter
+    pub fn get_activity_count(env: Env, user: Address, kind: ActivityKind) -> u64 {
+        activity_count(&env, &user, kind)
+    }
+
+    /// Get all badges owned by an address, with each type's transfer policy
+    pub fn get_badges(env: Env, owner: Address) -> Vec<OwnedBadge> {
         let user_badges_key = StorageKey::UserBadges(owner);
         let badge_ids: Vec<u64> = env
             .storage()
@@ -357,8 +1054,14 @@ impl ReputationBadges {
         let mut badges = Vec::new(&env);
         for i in 0..badge_ids.len() {
             if let Some(badge_id) = badge_ids.get(i) {
-                if let Some(badge) = env.storage().persistent().get(&StorageKey::Badge(badge_id)) {
-                    badges.push_back(badge);
+                let badge: Option<Badge> =
+                    env.storage().persistent().get(&StorageKey::Badge(badge_id));
+                if let Some(badge) = badge {
+                    let transfer_policy = transfer_policy(&env, &badge.badge_type);
+                    badges.push_back(OwnedBadge {
+                        badge,
+                        transfer_policy,
+                    });
                 }
             }
         }
@@ -371,6 +1074,36 @@ impl ReputationBadges {
         env.storage().persistent().has(&ownership_key)
     }
 
+    /// Check if an owner has a specific badge type at `min_level` or above
+    pub fn has_badge_at_level(
+        env: Env,
+        owner: Address,
+        badge_type: BadgeType,
+        min_level: u32,
+    ) -> bool {
+        find_badge(&env, &owner, &badge_type)
+            .map(|badge| badge.level >= min_level)
+            .unwrap_or(false)
+    }
+
+    /// Raise a badge to `new_level`, keeping its ID (admin only)
+    pub fn upgrade_badge(env: Env, badge_id: u64, new_level: u32) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        let badge: Badge = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::Badge(badge_id))
+            .ok_or(Error::BadgeNotFound)?;
+        upgrade_internal(&env, badge, new_level)
+    }
+
+    /// Get a badge's level, if the badge exists
+    pub fn get_badge_level(env: Env, badge_id: u64) -> Option<u32> {
+        Self::get_badge(env, badge_id).map(|badge| badge.level)
+    }
+
     /// Get the total number of badges owned by an address
     pub fn get_badge_count(env: Env, owner: Address) -> u32 {
         let user_badges_key = StorageKey::UserBadges(owner);
@@ -382,7 +1115,29 @@ impl ReputationBadges {
         badge_ids.len()
     }
 
-    /// Transfer a badge to another address (optional feature)
+    /// Get the transfer policy for a badge type
+    pub fn get_transfer_policy(env: Env, badge_type: BadgeType) -> TransferPolicy {
+        transfer_policy(&env, &badge_type)
+    }
+
+    /// Approve the transfer of an `AdminApproved` badge to `to` (admin only)
+    pub fn approve_transfer(env: Env, badge_id: u64, to: Address) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&StorageKey::Badge(badge_id)) {
+            return Err(Error::BadgeNotFound);
+        }
+        env.storage()
+            .persistent()
+            .set(&StorageKey::TransferApproval(badge_id), &to);
+
+        TransferApproved { badge_id, to }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Transfer a badge to another address, subject to its type's transfer policy
     pub fn transfer_badge(env: Env, badge_id: u64, to: Address) -> Result<(), Error> {
         // Get the badge
         let badge_key = StorageKey::Badge(badge_id);
@@ -397,6 +1152,19 @@ impl ReputationBadges {
 
         let from = badge.owner.clone();
 
+        match transfer_policy(&env, &badge.badge_type) {
+            TransferPolicy::Transferable => {}
+            TransferPolicy::Soulbound => return Err(Error::TransferNotAllowed),
+            TransferPolicy::AdminApproved => {
+                let approval_key = StorageKey::TransferApproval(badge_id);
+                let approved: Option<Address> = env.storage().persistent().get(&approval_key);
+                if approved != Some(to.clone()) {
+                    return Err(Error::TransferNotAllowed);
+                }
+                env.storage().persistent().remove(&approval_key);
+            }
+        }
+
         // Check if recipient already owns this badge type
         let to_ownership_key = StorageKey::TypeOwnership(to.clone(), badge.badge_type.clone());
         if env
@@ -482,6 +1250,7 @@ impl ReputationBadges {
 
         let owner = badge.owner.clone();
         let badge_type = badge.badge_type.clone();
+        let level = badge.level;
 
         // Require auth from the current owner or admin
         // Since we don't have an admin defined in this contract, let's assume the owner
@@ -518,11 +1287,12 @@ impl ReputationBadges {
 
         // Emit BadgeRevoked event
         let event_payload = BadgeEvent {
-            event_version: 1,
+            event_version: BADGE_EVENT_VERSION,
             badge_id,
-            badge_type: badge_type as u32,
+            badge_type: badge_type.id(),
             owner: owner.clone(),
             action: BadgeAction::Revoke,
+            level,
             timestamp: env.ledger().timestamp(),
         };
         env.events()
@@ -538,14 +1308,6 @@ impl ReputationBadges {
             .get(&StorageKey::BadgeCount)
             .unwrap_or(0u64)
     }
-
-    // Internal helper to get user reputation
-    fn get_user_reputation_internal(env: &Env, user: &Address) -> i128 {
-        env.storage()
-            .persistent()
-            .get(&StorageKey::UserReputation(user.clone()))
-            .unwrap_or(0i128)
-    }
 }
 #[cfg(test)]
 mod test;
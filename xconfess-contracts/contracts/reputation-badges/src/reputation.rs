@@ -0,0 +1,293 @@
+//! Rule-driven reputation scoring.
+//!
+//! Attested activity adds a configurable weight per unit to one of four
+//! categories. Category scores decay towards zero by `decay_bps` every
+//! `decay_period_secs`; decay is applied lazily whenever a score is read or
+//! updated. A new decay rate applies from the time it is set: each replaced
+//! rate is kept with the time it ended, and scores last updated before then
+//! decay at that rate up to it. Only the last `MAX_DECAY_EPOCHS` replaced
+//! rates are kept; a score older than all of them decays at the oldest kept
+//! rate from its last update. Manual admin adjustments are kept separately
+//! and never decay.
+//! The reported total is clamped to the configured floor and ceiling.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{ActivityKind, Error, StorageKey};
+
+const BPS_DENOMINATOR: u32 = 10_000;
+/// Fixed-point scale for decay factors.
+const SCALE: i128 = 1_000_000_000_000_000_000;
+/// Replaced decay rates kept, bounding the history walked on every read.
+pub const MAX_DECAY_EPOCHS: u32 = 32;
+
+/// Reputation added per unit of attested activity, by category
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationWeights {
+    pub confession_posted: i128,
+    pub reaction_received: i128,
+    pub tip_received: i128,
+    pub report_upheld: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationConfig {
+    pub weights: ReputationWeights,
+    /// Share of each category score lost per decay period; 0 disables decay
+    pub decay_bps: u32,
+    pub decay_period_secs: u64,
+    pub floor: i128,
+    pub ceiling: i128,
+}
+
+impl Default for ReputationConfig {
+    fn default() -> Self {
+        ReputationConfig {
+            weights: ReputationWeights {
+                confession_posted: 10,
+                reaction_received: 1,
+                tip_received: 5,
+                report_upheld: -25,
+            },
+            decay_bps: 0,
+            decay_period_secs: 30 * 24 * 60 * 60,
+            floor: -1_000_000,
+            ceiling: 1_000_000,
+        }
+    }
+}
+
+impl ReputationConfig {
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.floor > self.ceiling
+            || self.decay_bps > BPS_DENOMINATOR
+            || (self.decay_bps > 0 && self.decay_period_secs == 0)
+        {
+            return Err(Error::InvalidReputationConfig);
+        }
+        Ok(())
+    }
+}
+
+/// A replaced decay rate and the ledger time it stopped applying
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecayEpoch {
+    pub decay_bps: u32,
+    pub decay_period_secs: u64,
+    pub until: u64,
+}
+
+/// Activity-driven category scores as of `updated_at`
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ActivityScore {
+    pub confession_posted: i128,
+    pub reaction_received: i128,
+    pub tip_received: i128,
+    pub report_upheld: i128,
+    pub updated_at: u64,
+}
+
+/// Contribution of each category to a user's reputation, decayed to now
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationBreakdown {
+    pub confession_posted: i128,
+    pub reaction_received: i128,
+    pub tip_received: i128,
+    pub report_upheld: i128,
+    pub manual: i128,
+    /// Sum of all categories, clamped to the configured floor and ceiling
+    pub total: i128,
+}
+
+pub fn config(env: &Env) -> ReputationConfig {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::ReputationConfig)
+        .unwrap_or_default()
+}
+
+fn decay_history(env: &Env) -> Vec<DecayEpoch> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::DecayHistory)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Store `new_config`, closing the current decay epoch if the rate changes
+/// and dropping the oldest epoch beyond `MAX_DECAY_EPOCHS`.
+pub fn set_config(env: &Env, new_config: &ReputationConfig) {
+    let old = config(env);
+    if old.decay_bps != new_config.decay_bps
+        || old.decay_period_secs != new_config.decay_period_secs
+    {
+        let mut history = decay_history(env);
+        history.push_back(DecayEpoch {
+            decay_bps: old.decay_bps,
+            decay_period_secs: old.decay_period_secs,
+            until: env.ledger().timestamp(),
+        });
+        if history.len() > MAX_DECAY_EPOCHS {
+            history.pop_front();
+        }
+        env.storage()
+            .persistent()
+            .set(&StorageKey::DecayHistory, &history);
+    }
+    env.storage()
+        .persistent()
+        .set(&StorageKey::ReputationConfig, new_config);
+}
+
+/// Sum of manual admin adjustments.
+pub fn manual(env: &Env, user: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::UserReputation(user.clone()))
+        .unwrap_or(0i128)
+}
+
+/// Whether the user has any activity score or manual adjustment stored.
+pub fn has_record(env: &Env, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&StorageKey::ActivityScore(user.clone()))
+        || env
+            .storage()
+            .persistent()
+            .has(&StorageKey::UserReputation(user.clone()))
+}
+
+pub fn set_manual(env: &Env, user: &Address, value: i128) {
+    env.storage()
+        .persistent()
+        .set(&StorageKey::UserReputation(user.clone()), &value);
+}
+
+/// `SCALE * (1 - decay_bps / 10_000) ^ periods`, by repeated squaring.
+fn decay_factor(decay_bps: u32, periods: u64) -> i128 {
+    let mut base = (BPS_DENOMINATOR - decay_bps) as i128 * (SCALE / BPS_DENOMINATOR as i128);
+    let mut factor = SCALE;
+    let mut remaining = periods;
+    while remaining > 0 && factor > 0 {
+        if remaining & 1 == 1 {
+            factor = factor * base / SCALE;
+        }
+        base = base * base / SCALE;
+        remaining >>= 1;
+    }
+    factor
+}
+
+/// `value * factor / SCALE` without overflowing for any `i128` value.
+fn apply_factor(value: i128, factor: i128) -> i128 {
+    value / SCALE * factor + value % SCALE * factor / SCALE
+}
+
+/// Decay `score` by `decay_bps` for each whole `period_secs` between `from`
+/// and `to`, returning the time up to which it has been decayed.
+fn decay(
+    score: &mut ActivityScore,
+    decay_bps: u32,
+    period_secs: u64,
+    from: u64,
+    to: u64,
+) -> u64 {
+    if decay_bps == 0 || period_secs == 0 {
+        return to;
+    }
+    let periods = to.saturating_sub(from) / period_secs;
+    if periods > 0 {
+        let factor = decay_factor(decay_bps, periods);
+        score.confession_posted = apply_factor(score.confession_posted, factor);
+        score.reaction_received = apply_factor(score.reaction_received, factor);
+        score.tip_received = apply_factor(score.tip_received, factor);
+        score.report_upheld = apply_factor(score.report_upheld, factor);
+    }
+    from + periods * period_secs
+}
+
+/// The user's category scores with decay applied up to the current ledger
+/// time. Time before a rate change decays at the rate then in force, with
+/// any partial period at the change dropped. Under the current rate
+/// `updated_at` advances by whole periods only, so partial periods carry
+/// over to the next read.
+pub fn activity_score(env: &Env, config: &ReputationConfig, user: &Address) -> ActivityScore {
+    let now = env.ledger().timestamp();
+    let mut score: ActivityScore = env
+        .storage()
+        .persistent()
+        .get(&StorageKey::ActivityScore(user.clone()))
+        .unwrap_or(ActivityScore {
+            updated_at: now,
+            ..Default::default()
+        });
+
+    let mut decayed_to = score.updated_at;
+    for epoch in decay_history(env).iter() {
+        if epoch.until > decayed_to {
+            let (bps, period) = (epoch.decay_bps, epoch.decay_period_secs);
+            decay(&mut score, bps, period, decayed_to, epoch.until);
+            decayed_to = epoch.until;
+        }
+    }
+
+    let (bps, period) = (config.decay_bps, config.decay_period_secs);
+    score.updated_at = decay(&mut score, bps, period, decayed_to, now);
+    score
+}
+
+/// Add `count` units of `kind` to the user's decayed category scores.
+/// Activity kinds without a reputation category leave the score unchanged.
+pub fn record(env: &Env, user: &Address, kind: ActivityKind, count: u32) {
+    let config = config(env);
+    let weights = &config.weights;
+    let mut score = activity_score(env, &config, user);
+
+    let (category, weight) = match kind {
+        ActivityKind::ConfessionPosted => {
+            (&mut score.confession_posted, weights.confession_posted)
+        }
+        ActivityKind::ReactionReceived => {
+            (&mut score.reaction_received, weights.reaction_received)
+        }
+        ActivityKind::TipReceived => (&mut score.tip_received, weights.tip_received),
+        ActivityKind::ReportUpheld => (&mut score.report_upheld, weights.report_upheld),
+        ActivityKind::ReactionGiven | ActivityKind::TipSent => return,
+    };
+    *category = category.saturating_add(weight.saturating_mul(count as i128));
+
+    env.storage()
+        .persistent()
+        .set(&StorageKey::ActivityScore(user.clone()), &score);
+}
+
+pub fn breakdown(env: &Env, user: &Address) -> ReputationBreakdown {
+    let config = config(env);
+    let score = activity_score(env, &config, user);
+    let manual = manual(env, user);
+    let total = score
+        .confession_posted
+        .saturating_add(score.reaction_received)
+        .saturating_add(score.tip_received)
+        .saturating_add(score.report_upheld)
+        .saturating_add(manual)
+        .clamp(config.floor, config.ceiling);
+
+    ReputationBreakdown {
+        confession_posted: score.confession_posted,
+        reaction_received: score.reaction_received,
+        tip_received: score.tip_received,
+        report_upheld: score.report_upheld,
+        manual,
+        total,
+    }
+}
+
+pub fn total(env: &Env, user: &Address) -> i128 {
+    breakdown(env, user).total
+}
@@ -1,13 +1,29 @@
-use soroban_sdk::{Env, BytesN};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, String};
 use xconfess_contract::configurable::Config;
+use xconfess_contract::{Error, XConfessContract, XConfessContractClient};
+
+fn setup() -> (Env, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(XConfessContract, ());
+    let owner = Address::generate(&env);
+    XConfessContractClient::new(&env, &contract_id).initialize(&owner);
+
+    env.as_contract(&contract_id, || {
+        Config::init(&env, 1000, 100, 3600);
+    });
+
+    (env, contract_id, owner)
+}
 
 #[test]
 fn test_config_update_success() {
-    let env = Env::default();
-    let mut cfg = Config::init(env.clone(), 1000, 100, 3600);
-    let admin = BytesN::from_array(&env, &[0u8;32]);
+    let (env, contract_id, owner) = setup();
 
-    cfg.update(env.clone(), admin.clone(), 2000, 200, 7200);
+    let cfg = env.as_contract(&contract_id, || {
+        Config::update(&env, &owner, 2000, 200, 7200);
+        Config::load(&env)
+    });
 
     assert_eq!(cfg.limits, 2000);
     assert_eq!(cfg.threshold, 200);
@@ -15,32 +31,72 @@ fn test_config_update_success() {
 }
 
 #[test]
-#[should_panic(expected = "unauthorized: not admin")]
+#[should_panic(expected = "unauthorized: not owner")]
 fn test_config_update_unauthorized() {
-    let env = Env::default();
-    let mut cfg = Config::init(env.clone(), 1000, 100, 3600);
-    let fake_admin = BytesN::from_array(&env, &[1u8;32]);
+    let (env, contract_id, _owner) = setup();
+    let outsider = Address::generate(&env);
 
-    cfg.update(env.clone(), fake_admin, 2000, 200, 7200);
+    env.as_contract(&contract_id, || {
+        Config::update(&env, &outsider, 2000, 200, 7200);
+    });
 }
 
 #[test]
 #[should_panic(expected = "limits exceed max")]
 fn test_config_update_out_of_bounds() {
-    let env = Env::default();
-    let mut cfg = Config::init(env.clone(), 1000, 100, 3600);
-    let admin = BytesN::from_array(&env, &[0u8;32]);
+    let (env, contract_id, owner) = setup();
 
-    cfg.update(env.clone(), admin, 20_000, 200, 7200);
+    env.as_contract(&contract_id, || {
+        Config::update(&env, &owner, 20_000, 200, 7200);
+    });
 }
 
 #[test]
 #[should_panic(expected = "cooldown not elapsed")]
 fn test_config_update_cooldown() {
-    let env = Env::default();
-    let mut cfg = Config::init(env.clone(), 1000, 100, 3600);
-    let admin = BytesN::from_array(&env, &[0u8;32]);
+    let (env, contract_id, owner) = setup();
+    env.ledger().set_timestamp(10_000);
+
+    env.as_contract(&contract_id, || {
+        Config::update(&env, &owner, 2000, 200, 7200);
+    });
+    env.as_contract(&contract_id, || {
+        Config::update(&env, &owner, 3000, 300, 7200);
+    });
+}
+
+#[test]
+fn test_update_config_entrypoint_bounds_content() {
+    let (env, contract_id, owner) = setup();
+    let client = XConfessContractClient::new(&env, &contract_id);
+
+    let updated = client.update_config(&owner, &4, &100, &3600);
+    assert_eq!(client.get_config(), updated);
+    assert_eq!(updated.limits, 4);
+
+    client.create(&String::from_str(&env, "four"));
+    let result = client.try_create(&String::from_str(&env, "five!"));
+    assert_eq!(result, Err(Ok(Error::ContentTooLong)));
+}
+
+#[test]
+fn test_update_config_entrypoint_rejects_non_owner() {
+    let (env, contract_id, _owner) = setup();
+    let client = XConfessContractClient::new(&env, &contract_id);
 
-    cfg.update(env.clone(), admin.clone(), 2000, 200, 7200);
-    cfg.update(env.clone(), admin.clone(), 3000, 300, 7200);
-}
\ No newline at end of file
+    let result = client.try_update_config(&Address::generate(&env), &2000, &200, &7200);
+    assert_eq!(result, Err(Ok(Error::NotOwner)));
+}
+
+#[test]
+#[should_panic(expected = "cooldown not elapsed")]
+fn test_config_update_cooldown_from_time_zero() {
+    let (env, contract_id, owner) = setup();
+
+    env.as_contract(&contract_id, || {
+        Config::update(&env, &owner, 2000, 200, 7200);
+    });
+    env.as_contract(&contract_id, || {
+        Config::update(&env, &owner, 3000, 300, 7200);
+    });
+}
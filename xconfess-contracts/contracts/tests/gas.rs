@@ -1,4 +1,4 @@
-use soroban_sdk::{Env, testutils::Budget};
+use soroban_sdk::{testutils::Address as _, Address, Env, String as SorobanString};
 
 use xconfess_contract::{XConfessContract, XConfessContractClient};
 
@@ -7,33 +7,37 @@ fn snapshot_gas_usage() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let contract_id = env.register_contract(None, XConfessContract);
+    let contract_id = env.register(XConfessContract, ());
     let client = XConfessContractClient::new(&env, &contract_id);
 
+    let owner = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.initialize(&owner);
+
     let mut report = serde_json::json!({});
 
     // CREATE
-    env.budget().reset_unlimited();
-    client.create(&"hello".into());
-    let create_cpu = env.budget().cpu_instruction_cost();
+    env.cost_estimate().budget().reset_unlimited();
+    client.create(&SorobanString::from_str(&env, "hello"));
+    let create_cpu = env.cost_estimate().budget().cpu_instruction_cost();
     report["create"] = create_cpu.into();
 
     // REACT
-    env.budget().reset_unlimited();
-    client.react(&1, &1);
-    let react_cpu = env.budget().cpu_instruction_cost();
+    env.cost_estimate().budget().reset_unlimited();
+    client.react(&user, &1, &1);
+    let react_cpu = env.cost_estimate().budget().cpu_instruction_cost();
     report["react"] = react_cpu.into();
 
     // REPORT
-    env.budget().reset_unlimited();
-    client.report(&1, &"spam".into());
-    let report_cpu = env.budget().cpu_instruction_cost();
+    env.cost_estimate().budget().reset_unlimited();
+    client.report(&user, &1, &SorobanString::from_str(&env, "spam"));
+    let report_cpu = env.cost_estimate().budget().cpu_instruction_cost();
     report["report"] = report_cpu.into();
 
     // RESOLVE
-    env.budget().reset_unlimited();
-    client.resolve(&1);
-    let resolve_cpu = env.budget().cpu_instruction_cost();
+    env.cost_estimate().budget().reset_unlimited();
+    client.resolve(&owner, &1);
+    let resolve_cpu = env.cost_estimate().budget().cpu_instruction_cost();
     report["resolve"] = resolve_cpu.into();
 
     std::fs::write(
@@ -41,4 +45,4 @@ fn snapshot_gas_usage() {
         serde_json::to_string_pretty(&report).unwrap(),
     )
     .unwrap();
-}
\ No newline at end of file
+}
@@ -1,26 +1,36 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, String as SorobanString};
+
+use xconfess_contract::{XConfessContract, XConfessContractClient};
+
 #[test]
 fn test_confession_pagination_full_walk() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, XConfessContract);
+    env.mock_all_auths();
+    let contract_id = env.register(XConfessContract, ());
     let client = XConfessContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
 
     for i in 0..15 {
-        client.create_confession(&format!("confession {}", i).into());
+        client.create(&SorobanString::from_str(&env, &format!("confession {}", i)));
     }
 
     let mut cursor = None;
     let mut total = 0;
 
     loop {
-        let (page, next) = client.list_confessions(&cursor, &4);
+        let page = client.list_confessions(&cursor, &4);
 
-        if page.is_empty() {
+        if page.items.is_empty() {
             break;
         }
 
-        total += page.len();
-        cursor = next;
+        total += page.items.len();
+        cursor = page.next_cursor;
+
+        if cursor.is_none() {
+            break;
+        }
     }
 
     assert_eq!(total, 15);
-}
\ No newline at end of file
+}
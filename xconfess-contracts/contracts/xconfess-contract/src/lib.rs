@@ -0,0 +1,478 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, Address, Env, String,
+    Symbol, Vec,
+};
+
+// The shared modules still publish through the deprecated tuple API so that
+// their event layout matches the other contracts that include them.
+#[allow(deprecated)]
+#[path = "../../access_control.rs"]
+pub mod access_control;
+#[allow(deprecated)]
+#[path = "../../configurable/mod.rs"]
+pub mod configurable;
+#[allow(deprecated)]
+#[path = "../../events.rs"]
+pub mod events;
+#[path = "../../pagination/mod.rs"]
+pub mod pagination;
+#[allow(deprecated)]
+#[path = "../../report.rs"]
+pub mod report;
+
+use configurable::Config;
+use pagination::confession::{
+    Confession, ConfessionError, ConfessionKey, MAX_CONFESSION_CONTENT_LEN,
+};
+use report::{ReportContract, ReportError};
+
+/// Reaction types accepted by `react`.
+pub const REACTION_LIKE: u32 = 1;
+pub const REACTION_DISLIKE: u32 = 2;
+
+/// Upper bound on `list_confessions` page size.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NotOwner = 1,
+    NotAuthorized = 2,
+    AlreadyAdmin = 3,
+    NotAdmin = 4,
+    NotInitialized = 5,
+    CannotDemoteOwner = 6,
+    CannotRevokeLastAdmin = 7,
+    InvalidOwnershipTransfer = 8,
+    ContentEmpty = 9,
+    ContentTooLong = 10,
+    ConfessionNotFound = 11,
+    InvalidReactionType = 12,
+    ReactionExists = 13,
+    ReasonEmpty = 14,
+    ReasonTooLong = 15,
+    ReportExists = 16,
+    CooldownActive = 17,
+    NoPendingReports = 18,
+    AlreadyResolved = 19,
+    InvalidPageSize = 20,
+}
+
+impl From<access_control::AccessError> for Error {
+    fn from(value: access_control::AccessError) -> Self {
+        match value {
+            access_control::AccessError::NotOwner => Self::NotOwner,
+            access_control::AccessError::NotAuthorized => Self::NotAuthorized,
+            access_control::AccessError::AlreadyAdmin => Self::AlreadyAdmin,
+            access_control::AccessError::NotAdmin => Self::NotAdmin,
+            access_control::AccessError::NotInitialized => Self::NotInitialized,
+            access_control::AccessError::CannotDemoteOwner => Self::CannotDemoteOwner,
+            access_control::AccessError::CannotRevokeLastAdmin => Self::CannotRevokeLastAdmin,
+            access_control::AccessError::InvalidOwnershipTransfer => Self::InvalidOwnershipTransfer,
+        }
+    }
+}
+
+impl From<ConfessionError> for Error {
+    fn from(value: ConfessionError) -> Self {
+        match value {
+            ConfessionError::ContentEmpty => Self::ContentEmpty,
+            ConfessionError::ContentTooLong => Self::ContentTooLong,
+        }
+    }
+}
+
+impl From<ReportError> for Error {
+    fn from(value: ReportError) -> Self {
+        match value {
+            ReportError::ReasonEmpty => Self::ReasonEmpty,
+            ReportError::ReasonTooLong => Self::ReasonTooLong,
+            ReportError::CooldownActive => Self::CooldownActive,
+            ReportError::ReportExists => Self::ReportExists,
+        }
+    }
+}
+
+/// Emitted by `create`: `("confession_created", id)` → `created_seq`
+#[contractevent(topics = ["confession_created"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfessionCreated {
+    #[topic]
+    pub id: u64,
+    pub created_seq: u64,
+}
+
+/// Emitted by `resolve`: `("resolved", id)` → `(caller, report_count)`
+#[contractevent(topics = ["resolved"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReportsResolved {
+    #[topic]
+    pub confession_id: u64,
+    pub caller: Address,
+    pub report_count: u32,
+}
+
+/// One page of `list_confessions`, shaped like the registry's `ConfessionPage`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfessionPage {
+    pub items: Vec<Confession>,
+    /// Cursor for the next page, or `None` once the last confession has been
+    /// returned.
+    pub next_cursor: Option<u64>,
+}
+
+/// Storage keys owned by the unified contract. Confession records live under
+/// `pagination::confession::ConfessionKey`; report timestamps under
+/// `report::ReportKey`; configuration under `configurable::Config`.
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// Reaction count per confession.
+    ReactionCount(u64),
+    /// Marks that `reactor` has reacted to a confession.
+    Reacted(u64, Address),
+    /// Number of reports awaiting resolution per confession.
+    PendingReports(u64),
+    /// Set once a confession's reports have been resolved.
+    Resolved(u64),
+}
+
+/// Content length bound: the configured `limits`, capped by the hard limit.
+fn max_content_len(env: &Env) -> u32 {
+    Config::load(env).limits.min(MAX_CONFESSION_CONTENT_LEN)
+}
+
+fn confession_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ConfessionKey::Counter)
+        .unwrap_or(0)
+}
+
+fn require_confession(env: &Env, id: u64) -> Result<Confession, Error> {
+    env.storage()
+        .persistent()
+        .get(&ConfessionKey::Registry(id))
+        .ok_or(Error::ConfessionNotFound)
+}
+
+fn is_resolved(env: &Env, id: u64) -> bool {
+    env.storage().persistent().has(&DataKey::Resolved(id))
+}
+
+fn reaction_symbol(env: &Env, reaction_type: u32) -> Result<Symbol, Error> {
+    match reaction_type {
+        REACTION_LIKE => Ok(Symbol::new(env, "like")),
+        REACTION_DISLIKE => Ok(Symbol::new(env, "dislike")),
+        _ => Err(Error::InvalidReactionType),
+    }
+}
+
+#[contract]
+pub struct XConfessContract;
+
+#[contractimpl]
+impl XConfessContract {
+    // ─────────────────────────────────────────────────────────────────────────
+    // Initialization & Admin Management
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Initialize the contract with an owner. Must be called exactly once after deployment.
+    pub fn initialize(env: Env, owner: Address) -> Result<(), Error> {
+        access_control::init_owner(&env, &owner)?;
+
+        Config::init(
+            &env,
+            configurable::config::DEFAULT_LIMITS,
+            configurable::config::DEFAULT_THRESHOLD,
+            configurable::config::DEFAULT_WINDOW,
+        );
+        Ok(())
+    }
+
+    /// Get the current owner address.
+    pub fn get_owner(env: Env) -> Result<Address, Error> {
+        access_control::get_owner(&env).map_err(Into::into)
+    }
+
+    /// Check if an address is the current owner.
+    pub fn is_owner(env: Env, address: Address) -> bool {
+        access_control::is_owner(&env, &address).unwrap_or(false)
+    }
+
+    /// Check if an address is an admin (not including the owner).
+    pub fn is_admin(env: Env, address: Address) -> bool {
+        access_control::is_admin(&env, &address)
+    }
+
+    /// Check if an address may resolve reports (owner or admin).
+    pub fn can_moderate(env: Env, address: Address) -> bool {
+        access_control::is_authorized(&env, &address).unwrap_or(false)
+    }
+
+    /// Get count of active admins (excluding the owner).
+    pub fn count_admins(env: Env) -> u32 {
+        access_control::count_admins(&env)
+    }
+
+    /// Get count of authorized addresses (owner + admins).
+    pub fn count_authorized(env: Env) -> u32 {
+        access_control::count_authorized(&env)
+    }
+
+    /// Grant admin role to an address (owner-only).
+    pub fn assign_admin(env: Env, caller: Address, target: Address) -> Result<(), Error> {
+        access_control::grant_admin(&env, &caller, &target).map_err(Into::into)
+    }
+
+    /// Alias of `assign_admin` kept for clients using the governance naming.
+    pub fn grant_admin(env: Env, caller: Address, target: Address) -> Result<(), Error> {
+        Self::assign_admin(env, caller, target)
+    }
+
+    /// Revoke admin role from an address (owner-only).
+    pub fn revoke_admin(env: Env, caller: Address, target: Address) -> Result<(), Error> {
+        access_control::revoke_admin(&env, &caller, &target).map_err(Into::into)
+    }
+
+    /// Transfer ownership to a new owner (current owner-only).
+    pub fn transfer_ownership(env: Env, caller: Address, new_owner: Address) -> Result<(), Error> {
+        access_control::transfer_ownership(&env, &caller, &new_owner).map_err(Into::into)
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Configuration
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Get the current contract configuration.
+    pub fn get_config(env: Env) -> Config {
+        Config::load(&env)
+    }
+
+    /// Update the contract configuration (owner-only, at most once per hour).
+    ///
+    /// `limits` bounds confession content length, capped by
+    /// `MAX_CONFESSION_CONTENT_LEN`. Out-of-bounds values and updates inside
+    /// the cooldown panic, as in `configurable::Config::update`.
+    ///
+    /// Emits: `("ConfigUpdate",)` → `(old_limits, old_threshold, old_window,
+    /// new_limits, new_threshold, new_window)`
+    pub fn update_config(
+        env: Env,
+        caller: Address,
+        limits: u32,
+        threshold: u32,
+        window: u32,
+    ) -> Result<Config, Error> {
+        // `Config::update` authenticates the owner; reject others with an
+        // error rather than its panic.
+        if !access_control::is_owner(&env, &caller)? {
+            return Err(Error::NotOwner);
+        }
+        Ok(Config::update(&env, &caller, limits, threshold, window))
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Confession lifecycle
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Store a new anonymous confession and return its ID.
+    ///
+    /// Emits: `("confession_created", id)` → `created_seq`
+    pub fn create(env: Env, content: String) -> Result<u64, Error> {
+        if content.len() > max_content_len(&env) {
+            return Err(Error::ContentTooLong);
+        }
+
+        let id = pagination::confession::create(&env, content)?;
+
+        ConfessionCreated {
+            id,
+            created_seq: env.ledger().sequence() as u64,
+        }
+        .publish(&env);
+
+        Ok(id)
+    }
+
+    /// React to a confession. Each reactor may react once per confession.
+    ///
+    /// Emits the versioned `ReactionEvent` from `events.rs`.
+    pub fn react(
+        env: Env,
+        reactor: Address,
+        confession_id: u64,
+        reaction_type: u32,
+    ) -> Result<(), Error> {
+        reactor.require_auth();
+
+        require_confession(&env, confession_id)?;
+        let reaction = reaction_symbol(&env, reaction_type)?;
+
+        let reacted_key = DataKey::Reacted(confession_id, reactor.clone());
+        if env.storage().persistent().has(&reacted_key) {
+            return Err(Error::ReactionExists);
+        }
+        env.storage().persistent().set(&reacted_key, &reaction_type);
+
+        let count_key = DataKey::ReactionCount(confession_id);
+        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage().persistent().set(&count_key, &(count + 1));
+
+        events::emit_reaction(&env, confession_id, reactor, reaction, None);
+
+        Ok(())
+    }
+
+    /// Report a confession for moderation. Each reporter may report a
+    /// confession once; resolved confessions cannot be reported again.
+    pub fn report(
+        env: Env,
+        reporter: Address,
+        confession_id: u64,
+        reason: String,
+    ) -> Result<u64, Error> {
+        reporter.require_auth();
+
+        require_confession(&env, confession_id)?;
+        if is_resolved(&env, confession_id) {
+            return Err(Error::AlreadyResolved);
+        }
+        ReportContract::submit_report_with_reason(&env, &reporter, confession_id, reason)?;
+        let report_id = pagination::report::create(&env, confession_id);
+
+        let pending_key = DataKey::PendingReports(confession_id);
+        let pending: u32 = env.storage().persistent().get(&pending_key).unwrap_or(0);
+        env.storage().persistent().set(&pending_key, &(pending + 1));
+
+        Ok(report_id)
+    }
+
+    /// Resolve all pending reports against a confession (owner or admin).
+    ///
+    /// Emits: `("resolved", id)` → `(caller, report_count)`
+    pub fn resolve(env: Env, caller: Address, confession_id: u64) -> Result<(), Error> {
+        access_control::require_admin_or_owner(&env, &caller)?;
+
+        require_confession(&env, confession_id)?;
+        if is_resolved(&env, confession_id) {
+            return Err(Error::AlreadyResolved);
+        }
+
+        let pending_key = DataKey::PendingReports(confession_id);
+        let pending: u32 = env.storage().persistent().get(&pending_key).unwrap_or(0);
+        if pending == 0 {
+            return Err(Error::NoPendingReports);
+        }
+
+        env.storage().persistent().remove(&pending_key);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Resolved(confession_id), &env.ledger().timestamp());
+
+        ReportsResolved {
+            confession_id,
+            caller,
+            report_count: pending,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Reads
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Get a confession by ID.
+    pub fn get_confession(env: Env, id: u64) -> Result<Confession, Error> {
+        require_confession(&env, id)
+    }
+
+    /// Get the total number of confessions created.
+    pub fn get_confession_count(env: Env) -> u64 {
+        confession_count(&env)
+    }
+
+    /// Get the number of reactions recorded against a confession.
+    pub fn get_reaction_count(env: Env, confession_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReactionCount(confession_id))
+            .unwrap_or(0)
+    }
+
+    /// Get the number of reports awaiting resolution for a confession.
+    pub fn get_pending_reports(env: Env, confession_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingReports(confession_id))
+            .unwrap_or(0)
+    }
+
+    /// Whether a confession's reports have been resolved.
+    pub fn is_resolved(env: Env, confession_id: u64) -> bool {
+        is_resolved(&env, confession_id)
+    }
+
+    /// List confessions in ID order, starting after `cursor`.
+    pub fn list_confessions(
+        env: Env,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> Result<ConfessionPage, Error> {
+        if limit == 0 || limit > MAX_PAGE_SIZE {
+            return Err(Error::InvalidPageSize);
+        }
+
+        let total = confession_count(&env);
+        let mut items = Vec::new(&env);
+        let mut id = cursor.unwrap_or(0);
+
+        while id < total && items.len() < limit {
+            id += 1;
+            if let Some(confession) = env
+                .storage()
+                .persistent()
+                .get::<_, Confession>(&ConfessionKey::Registry(id))
+            {
+                items.push_back(confession);
+            }
+        }
+
+        Ok(ConfessionPage {
+            items,
+            next_cursor: if id < total { Some(id) } else { None },
+        })
+    }
+
+    // Event nonce reads for indexer reconciliation.
+
+    pub fn latest_confession_event_nonce(env: Env, confession_id: u64) -> u64 {
+        events::latest_confession_nonce(&env, confession_id)
+    }
+
+    pub fn latest_reaction_event_nonce(env: Env, confession_id: u64) -> u64 {
+        events::latest_reaction_nonce(&env, confession_id)
+    }
+
+    pub fn latest_report_event_nonce(env: Env, confession_id: u64) -> u64 {
+        ReportContract::latest_report_nonce(&env, confession_id)
+    }
+
+    pub fn latest_role_event_nonce(env: Env, user: Address, role: Symbol) -> u64 {
+        events::latest_role_nonce(&env, user, role)
+    }
+
+    pub fn latest_governance_event_nonce(env: Env, stream: Symbol) -> u64 {
+        events::latest_governance_nonce(&env, stream)
+    }
+
+    pub fn latest_badge_event_nonce(env: Env, badge_id: u64) -> u64 {
+        events::latest_badge_nonce(&env, badge_id)
+    }
+}